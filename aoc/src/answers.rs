@@ -0,0 +1,125 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    path::Path,
+};
+
+use serde::Deserialize;
+use utils::{Answer, Params, Part};
+
+/// Where the known answers are kept, relative to the working directory.
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// The known answers recorded in `answers.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    pub entries: Vec<Entry>,
+}
+
+/// The expected answers for a single day and input file.
+#[derive(Debug, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    /// Path to the input, relative to the inputs directory.
+    pub input: String,
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+    /// Whether the entry takes too long to check on every test run.
+    #[serde(default)]
+    pub slow: bool,
+    /// Values the day needs for this input besides the input itself, such as a smaller area to
+    /// search in an example.
+    #[serde(default)]
+    pub params: BTreeMap<String, Expected>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Integer(i64),
+    Text(String),
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+
+        toml::from_str(&contents).map_err(|err| format!("Invalid {}: {err}", path.display()))
+    }
+
+    /// The entry for an input at `path`, if it's in the inputs directory.
+    pub fn find_path(&self, day: u8, path: &Path) -> Option<&Entry> {
+        let input = path.strip_prefix(crate::input::DEFAULT_DIR).ok()?;
+
+        self.entries
+            .iter()
+            .find(|entry| entry.day == day && Path::new(&entry.input) == input)
+    }
+
+    /// The entry for a day's input, given relative to the inputs directory.
+    pub fn find(&self, day: u8, input: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.day == day && entry.input == input)
+    }
+}
+
+impl Entry {
+    pub fn expected(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// The values to run the entry's input with.
+    pub fn params(&self) -> Params {
+        self.params
+            .iter()
+            .fold(Params::default(), |params, (name, value)| {
+                params.with(name, value.to_string())
+            })
+    }
+
+    /// The parts this entry has an expected answer for.
+    pub fn parts(&self) -> Vec<Part> {
+        Part::ALL
+            .into_iter()
+            .filter(|part| self.expected(*part).is_some())
+            .collect()
+    }
+}
+
+impl Expected {
+    /// Reads an answer as written in the puzzle text, keeping whole numbers as numbers.
+    pub fn from_text(text: &str) -> Expected {
+        match text.parse() {
+            Ok(val) => Expected::Integer(val),
+            Err(_) => Expected::Text(text.to_owned()),
+        }
+    }
+
+    pub fn matches(&self, answer: &Answer) -> bool {
+        self.to_string() == answer.to_string()
+    }
+
+    /// The answer as it's written in `answers.toml`.
+    pub fn to_toml(&self) -> String {
+        match self {
+            Expected::Integer(val) => val.to_string(),
+            Expected::Text(text) => toml::Value::String(text.clone()).to_string(),
+        }
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Integer(val) => write!(f, "{val}"),
+            Expected::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
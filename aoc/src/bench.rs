@@ -0,0 +1,331 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use utils::{Part, Puzzle, SolveError};
+
+use crate::{
+    input::InputSource,
+    parallel::{panic_message, DayError},
+};
+
+/// Where benchmark results are kept between runs, relative to the working directory.
+pub const DEFAULT_HISTORY: &str = ".aoc/bench.json";
+
+/// Summary of the time taken by one step over every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+/// Every benchmark run recorded so far, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub runs: Vec<BenchRun>,
+}
+
+/// A step whose median got slower than the previous run by more than the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub step: &'static str,
+    pub previous: Stats,
+    pub current: Stats,
+    /// How much slower the median is, in percent.
+    pub change: f64,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        nanos.sort_unstable();
+
+        Stats {
+            min_ns: nanos[0],
+            median_ns: nanos[nanos.len() / 2],
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+impl DayBench {
+    pub fn steps(&self) -> Vec<(&'static str, Stats)> {
+        let mut steps = vec![("parse", self.parse)];
+        steps.extend(self.part1.map(|s| ("part 1", s)));
+        steps.extend(self.part2.map(|s| ("part 2", s)));
+        steps
+    }
+}
+
+/// Runs the puzzle `iterations` times, timing parsing and each part separately.
+pub fn bench(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<DayBench, SolveError> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+
+    for _ in 0..iterations.max(1) {
+        let run = puzzle.run(input, parts)?;
+        parse.push(run.parse);
+
+        for part in run.parts {
+            // A part that fails is failing fast, which would only make a misleading benchmark.
+            part.answer?;
+
+            match part.part {
+                Part::One => part1.push(part.duration),
+                Part::Two => part2.push(part.duration),
+            }
+        }
+    }
+
+    let stats = |samples: &[Duration]| (!samples.is_empty()).then(|| Stats::from_samples(samples));
+
+    Ok(DayBench {
+        day: puzzle.day(),
+        parse: Stats::from_samples(&parse),
+        part1: stats(&part1),
+        part2: stats(&part2),
+    })
+}
+
+/// Loads the day's input and benchmarks it. A day that panics is caught, as `run_days` does, so
+/// one broken day doesn't lose the timings of the others.
+pub fn bench_day(
+    puzzle: &dyn Puzzle,
+    input: &InputSource,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<DayBench, DayError> {
+    let data = input.load(puzzle.day()).map_err(DayError::Input)?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| bench(puzzle, &data, parts, iterations))) {
+        Ok(result) => result.map_err(DayError::Solve),
+        Err(payload) => Err(DayError::Panic(panic_message(payload.as_ref()))),
+    }
+}
+
+impl BenchRun {
+    pub fn new(iterations: usize, days: Vec<DayBench>) -> BenchRun {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        BenchRun {
+            timestamp,
+            iterations,
+            days,
+        }
+    }
+}
+
+impl History {
+    /// Loads the history from `path`, starting afresh if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<History, String> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+
+        serde_json::from_str(&contents).map_err(|err| format!("Invalid {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Could not create {}: {err}", parent.display()))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self).expect("History is always serializable");
+        fs::write(path, contents)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))
+    }
+
+    /// The most recent result for the given day.
+    pub fn latest(&self, day: u8) -> Option<&DayBench> {
+        self.runs
+            .iter()
+            .rev()
+            .find_map(|run| run.days.iter().find(|d| d.day == day))
+    }
+
+    /// Compares the medians of `current` against the latest recorded result for the same day.
+    pub fn regressions(&self, current: &DayBench, threshold: f64) -> Vec<Regression> {
+        let Some(previous) = self.latest(current.day) else {
+            return vec![];
+        };
+
+        let previous_steps = previous.steps();
+
+        current
+            .steps()
+            .into_iter()
+            .filter_map(|(step, stats)| {
+                let (_, previous) = previous_steps.iter().find(|(s, _)| *s == step)?;
+                let change = percent_change(previous.median_ns, stats.median_ns);
+
+                (change > threshold).then_some(Regression {
+                    day: current.day,
+                    step,
+                    previous: *previous,
+                    current: stats,
+                    change,
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn percent_change(previous_ns: u64, current_ns: u64) -> f64 {
+    if previous_ns == 0 {
+        return 0.0;
+    }
+
+    (current_ns as f64 - previous_ns as f64) / previous_ns as f64 * 100.0
+}
+
+/// Formats a number of nanoseconds with a unit that keeps it readable.
+pub fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use utils::{Answer, ParseError, Part, Solution, SolveError};
+
+    use super::{bench_day, format_nanos, BenchRun, DayBench, History, Stats};
+    use crate::{input::InputSource, parallel::DayError};
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const DAY: u8 = 1;
+
+        type Input = ();
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Result<Answer, SolveError> {
+            Ok(1.into())
+        }
+
+        fn part2(_: &()) -> Result<Answer, SolveError> {
+            panic!("part 2 is not done")
+        }
+    }
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: median_ns,
+            median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 2, 4].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 3);
+        assert_eq!(stats.max_ns, 5);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let history = History {
+            runs: vec![BenchRun::new(
+                1,
+                vec![DayBench {
+                    day: 1,
+                    parse: stats(100),
+                    part1: Some(stats(100)),
+                    part2: None,
+                }],
+            )],
+        };
+
+        let current = DayBench {
+            day: 1,
+            parse: stats(105),
+            part1: Some(stats(150)),
+            part2: Some(stats(1000)),
+        };
+
+        let regressions = history.regressions(&current, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].step, "part 1");
+        assert_eq!(regressions[0].change, 50.0);
+    }
+
+    #[test]
+    fn test_failing_days() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.txt", std::process::id()));
+        std::fs::write(&path, "\n").unwrap();
+        let input = InputSource::Path(path.clone());
+
+        let result = bench_day(&Unfinished, &input, &[Part::One], 2).unwrap();
+        assert!(result.part1.is_some());
+        assert!(matches!(
+            bench_day(&Unfinished, &input, &Part::ALL, 2),
+            Err(DayError::Panic(message)) if message == "part 2 is not done"
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            bench_day(&Unfinished, &input, &[Part::One], 2),
+            Err(DayError::Input(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_nanos() {
+        assert_eq!(format_nanos(999), "999ns");
+        assert_eq!(format_nanos(1_500), "1.50µs");
+        assert_eq!(format_nanos(2_000_000), "2.00ms");
+        assert_eq!(format_nanos(3_250_000_000), "3.25s");
+    }
+}
@@ -0,0 +1,311 @@
+use std::path::PathBuf;
+
+use aoc::{bench, input::InputSource, output::Format};
+use day_01::Width;
+use utils::{Params, Part};
+
+pub const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run [DAY]    Run a single day, or every day if none is given
+  bench [DAY]  Time parsing and each part of a single day, or every day
+  fetch [DAY]  Download the input for a single day, or every day, unless it's already in inputs/
+  submit DAY PART
+               Run one part on inputs/day_XX.txt and submit the answer, unless earlier
+               submissions show it's wrong
+  new DAY      Create days/day_XX from the template and register it with the runner
+  example DAY FILE
+               Save the example from a puzzle description saved as FILE to
+               inputs/examples/day_XX.txt, and its answers to answers.toml
+  report DAY   Describe a day's input, for days that have more to say than the answers
+
+Options for `run`:
+  --all        Run every day in sequence
+  --part N     Only run part N (1 or 2)
+  --input PATH Read the input from PATH (`-` for stdin) instead of inputs/day_XX.txt
+  --jobs N     Run up to N days at once, then print a summary checked against answers.toml
+  --format F   Print answers as `human` readable lines (default) or one `json` object per line
+  --stats      Also print what each part counted and timed, when built with `--features trace`
+  --width W    Count day 1's calories in `u32` (default), `u64` or `u128`
+  --param NAME=VALUE
+               Give a day a value its input doesn't say, such as day 15's `row` and `max`.
+               Inputs with an entry in answers.toml also take the values recorded there
+
+Options for `report`:
+  --input PATH Read the input from PATH (`-` for stdin) instead of inputs/day_XX.txt
+  --width W    Count day 1's calories in `u32` (default), `u64` or `u128`
+
+Options for `bench`:
+  --part N          Only time part N (1 or 2)
+  --iterations N    Number of times to run each day (default 10)
+  --threshold PCT   Flag steps whose median slowed down by more than PCT percent (default 10)
+  --history PATH    Where results are kept between runs (default .aoc/bench.json)
+  --no-save         Compare against the history without recording this run
+
+Options for `example`:
+  --block N    Use the Nth code block on the page as the example (default 1)
+  --force      Replace an example or answers that were already saved
+
+Fetching inputs and submitting answers needs the session cookie of a logged in account, read
+from the AOC_SESSION environment variable or .aoc/session. Submissions are recorded in
+.aoc/submissions.json.
+";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: Option<u8>,
+        parts: Vec<Part>,
+        input: InputSource,
+        format: Format,
+        jobs: Option<usize>,
+        stats: bool,
+        width: Option<Width>,
+        params: Params,
+    },
+    Bench {
+        day: Option<u8>,
+        parts: Vec<Part>,
+        iterations: usize,
+        threshold: f64,
+        history: PathBuf,
+        save: bool,
+    },
+    Fetch {
+        day: Option<u8>,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+    },
+    New {
+        day: u8,
+    },
+    Example {
+        day: u8,
+        page: PathBuf,
+        block: usize,
+        force: bool,
+    },
+    Report {
+        day: u8,
+        input: InputSource,
+        width: Option<Width>,
+    },
+    Help,
+}
+
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("bench") => parse_bench(args),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some("new") => parse_new(args),
+        Some("example") => parse_example(args),
+        Some("report") => parse_report(args),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("Unknown command: {other}")),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut parts = Part::ALL.to_vec();
+    let mut input = InputSource::Default;
+    let mut format = Format::Human;
+    let mut jobs = None;
+    let mut stats = false;
+    let mut width = None;
+    let mut params = Params::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => parts = vec![parse_part(&value(&mut args, &arg)?)?],
+            "--input" => input = InputSource::from_arg(&value(&mut args, &arg)?),
+            "--format" => format = Format::from_arg(&value(&mut args, &arg)?)?,
+            "--jobs" => jobs = Some(parse_number(&value(&mut args, &arg)?)?),
+            "--stats" => stats = true,
+            "--width" => width = Some(Width::from_arg(&value(&mut args, &arg)?)?),
+            "--param" => {
+                let param = value(&mut args, &arg)?;
+                let (name, value) = param
+                    .split_once('=')
+                    .ok_or(format!("Invalid parameter: {param} (expected NAME=VALUE)"))?;
+                params.set(name, value);
+            }
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    if all && day.is_some() {
+        return Err("Cannot combine a day with --all".into());
+    }
+
+    if day.is_none() && input != InputSource::Default {
+        return Err("--input can only be used when running a single day".into());
+    }
+
+    if jobs == Some(0) {
+        return Err("--jobs must be at least 1".into());
+    }
+
+    Ok(Command::Run {
+        day,
+        parts,
+        input,
+        format,
+        jobs,
+        stats,
+        width,
+        params,
+    })
+}
+
+fn parse_bench<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut day = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut iterations = 10;
+    let mut threshold: f64 = 10.0;
+    let mut history = PathBuf::from(bench::DEFAULT_HISTORY);
+    let mut save = true;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![parse_part(&value(&mut args, &arg)?)?],
+            "--iterations" => iterations = parse_number(&value(&mut args, &arg)?)?,
+            "--threshold" => threshold = parse_number(&value(&mut args, &arg)?)?,
+            "--history" => history = value(&mut args, &arg)?.into(),
+            "--no-save" => save = false,
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".into());
+    }
+
+    // NaN fails this as well, since it compares false to everything.
+    if !(threshold > 0.0 && threshold.is_finite()) {
+        return Err("--threshold must be a positive percentage".into());
+    }
+
+    Ok(Command::Bench {
+        day,
+        parts,
+        iterations,
+        threshold,
+        history,
+        save,
+    })
+}
+
+fn parse_fetch<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let day = args.next().map(|arg| parse_day(&arg)).transpose()?;
+
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument: {arg}")),
+        None => Ok(Command::Fetch { day }),
+    }
+}
+
+fn parse_submit<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let day = parse_day(&args.next().ok_or("Missing the day to submit")?)?;
+    let part = parse_part(&args.next().ok_or("Missing the part to submit")?)?;
+
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument: {arg}")),
+        None => Ok(Command::Submit { day, part }),
+    }
+}
+
+fn parse_new<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let day = parse_day(&args.next().ok_or("Missing the day to create")?)?;
+
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument: {arg}")),
+        None => Ok(Command::New { day }),
+    }
+}
+
+fn parse_example<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut day = None;
+    let mut page = None;
+    let mut block = 1;
+    let mut force = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--block" => block = parse_number(&value(&mut args, &arg)?)?,
+            "--force" => force = true,
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ if page.is_none() => page = Some(PathBuf::from(arg)),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    if block == 0 {
+        return Err("--block must be at least 1".into());
+    }
+
+    Ok(Command::Example {
+        day: day.ok_or("Missing the day of the example")?,
+        page: page.ok_or("Missing the saved puzzle description")?,
+        block,
+        force,
+    })
+}
+
+fn parse_report<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut day = None;
+    let mut input = InputSource::Default;
+    let mut width = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = InputSource::from_arg(&value(&mut args, &arg)?),
+            "--width" => width = Some(Width::from_arg(&value(&mut args, &arg)?)?),
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    Ok(Command::Report {
+        day: day.ok_or("Missing the day to report on")?,
+        input,
+        width,
+    })
+}
+
+/// Takes the value following `flag`.
+fn value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String, String> {
+    args.next().ok_or(format!("Missing value for {flag}"))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number: {value}"))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day: {value}")),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part: {value}")),
+    }
+}
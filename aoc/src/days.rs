@@ -0,0 +1,55 @@
+use day_01::Width;
+use utils::Puzzle;
+
+/// Every solved day, in order.
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().find(|p| p.day() == day).copied()
+}
+
+/// The given day, or every day if none is given.
+pub fn select(day: Option<u8>) -> Result<Vec<&'static dyn Puzzle>, String> {
+    match day {
+        Some(day) => find(day)
+            .map(|puzzle| vec![puzzle])
+            .ok_or(format!("Day {day} has not been solved yet")),
+        None => Ok(DAYS.to_vec()),
+    }
+}
+
+/// Swaps day 1 for one counting calories in `width`, if one is given. Only day 1 counts anything
+/// in a width of its choosing, so it's refused for every other day.
+pub fn with_width(
+    puzzles: Vec<&'static dyn Puzzle>,
+    width: Option<Width>,
+) -> Result<Vec<&'static dyn Puzzle>, String> {
+    let Some(width) = width else {
+        return Ok(puzzles);
+    };
+
+    puzzles
+        .into_iter()
+        .map(|puzzle| match puzzle.day() {
+            1 => Ok(width.puzzle()),
+            day => Err(format!("--width only applies to day 1, not day {day}")),
+        })
+        .collect()
+}
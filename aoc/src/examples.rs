@@ -0,0 +1,340 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use utils::Part;
+
+use crate::{
+    answers::{self, Answers, Expected},
+    input,
+};
+
+/// What a saved puzzle description gives away about its example.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Page {
+    /// Every `<pre><code>` block in the description, in order. The first is usually the example.
+    pub blocks: Vec<String>,
+    /// The last emphasised code in each part's description, which is almost always the answer
+    /// for the example. Part 2 is only there once part 1 has been solved.
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+impl Page {
+    pub fn expected(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Picks the code blocks and answers out of a puzzle description saved from the site.
+pub fn extract(html: &str) -> Page {
+    let mut articles = between(html, "<article", "</article>");
+    // A page saved without its surrounding layout may just be the description itself.
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let blocks = articles
+        .iter()
+        .flat_map(|article| between(article, "<pre><code>", "</code></pre>"))
+        .map(|block| text(block).trim_end().to_owned() + "\n")
+        .collect();
+
+    let answer = |index: usize| {
+        let article = articles.get(index)?;
+        let (_, last) = [
+            ("<code><em>", "</em></code>"),
+            ("<em><code>", "</code></em>"),
+        ]
+        .iter()
+        .filter_map(|(start, end)| {
+            let from = article.rfind(start)? + start.len();
+            let to = from + article[from..].find(end)?;
+            Some((from, &article[from..to]))
+        })
+        .max_by_key(|(from, _)| *from)?;
+
+        Some(Expected::from_text(text(last).trim()))
+    };
+
+    Page {
+        blocks,
+        part1: answer(0),
+        part2: answer(1),
+    }
+}
+
+/// Writes `example` to the day's example input, and records whichever of its answers are known in
+/// `answers.toml` so the regression tests check them. Both are only replaced if `force` is set,
+/// apart from the empty example input left by `aoc new`. Returns every file that was changed.
+pub fn save(
+    root: &Path,
+    day: u8,
+    example: &str,
+    page: &Page,
+    force: bool,
+) -> Result<Vec<PathBuf>, String> {
+    let input_name = format!("examples/day_{day:02}.txt");
+    let input_path = root.join(input::DEFAULT_DIR).join(&input_name);
+    let existing = fs::read_to_string(&input_path).unwrap_or_default();
+    if !existing.is_empty() && existing != example && !force {
+        return Err(format!(
+            "{} already has a different example, use --force to replace it",
+            input_path.display()
+        ));
+    }
+
+    let mut files = vec![(input_path, example.to_owned())];
+
+    if page.part1.is_some() || page.part2.is_some() {
+        let answers_path = root.join(answers::DEFAULT_PATH);
+        let answers = fs::read_to_string(&answers_path)
+            .map_err(|err| format!("Could not read {}: {err}", answers_path.display()))?;
+        let answers = record_answers(&answers, day, &input_name, page, force)?;
+
+        files.push((answers_path, answers));
+    }
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Could not create {}: {err}", parent.display()))?;
+        }
+
+        fs::write(path, contents)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Adds an entry for the day's example to the text of `answers.toml`, ahead of any entries for
+/// the same or later days. An entry that's already there is replaced if `force` is set.
+fn record_answers(
+    text: &str,
+    day: u8,
+    input: &str,
+    page: &Page,
+    force: bool,
+) -> Result<String, String> {
+    let mut entry = format!("[[answer]]\nday = {day}\ninput = \"{input}\"\n");
+    for part in Part::ALL {
+        if let Some(expected) = page.expected(part) {
+            entry += &format!("part{part} = {}\n", expected.to_toml());
+        }
+    }
+
+    // Split the file into the comments at the top and one chunk per entry, each running up to the
+    // next `[[answer]]` line so blank lines and comments stay with the entry above them.
+    let mut chunks = vec![String::new()];
+    for line in text.lines() {
+        if line.trim() == "[[answer]]" {
+            chunks.push(String::new());
+        }
+
+        let chunk = chunks.last_mut().unwrap();
+        chunk.push_str(line);
+        chunk.push('\n');
+    }
+
+    let mut position = chunks.len();
+    for (idx, chunk) in chunks.iter().enumerate().skip(1) {
+        let answers = toml::from_str::<Answers>(chunk)
+            .map_err(|err| format!("Invalid entry in {}: {err}", answers::DEFAULT_PATH))?;
+        let Some(existing) = answers.entries.first() else {
+            continue;
+        };
+
+        if existing.day == day && existing.input == input {
+            if !force {
+                return Err(format!(
+                    "{} already has answers for {input}, use --force to replace them",
+                    answers::DEFAULT_PATH
+                ));
+            }
+
+            let trailing = &chunk[chunk.trim_end().len()..];
+            chunks[idx] = entry.trim_end().to_owned() + trailing;
+            return Ok(chunks.concat());
+        }
+
+        if existing.day >= day {
+            position = position.min(idx);
+        }
+    }
+
+    match chunks.get(position) {
+        Some(_) => chunks.insert(position, entry + "\n"),
+        None => {
+            let last = chunks.last_mut().unwrap();
+            if !last.is_empty() && !last.ends_with("\n\n") {
+                last.push('\n');
+            }
+            chunks.push(entry);
+        }
+    }
+
+    Ok(chunks.concat())
+}
+
+/// Every stretch of `text` between an occurrence of `start` and the following `end`.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = text;
+
+    while let Some(from) = rest.find(start) {
+        rest = &rest[from + start.len()..];
+        let Some(to) = rest.find(end) else {
+            break;
+        };
+
+        found.push(&rest[..to]);
+        rest = &rest[to + end.len()..];
+    }
+
+    found
+}
+
+/// The text of some HTML, without its tags and with the usual entities decoded.
+fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    stripped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{extract, record_answers, save, Page};
+    use crate::answers::Expected;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2>
+<p>For example:</p>
+<pre><code>    [D]
+[N] [C]
+ 1   2
+
+move 1 from 2 to 1
+</code></pre>
+<p>Moves look like <code>move 1 from 2 to 1</code>, with <em>emphasis</em> on the &lt;words&gt;.</p>
+<pre><code>[<em>D</em>]
+</code></pre>
+<p>The top crates spell <code><em>CMZ</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>WSFTMRHPP</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The crates end up as <em><code>MCD</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    const ANSWERS: &str = "\
+# Expected answers.
+
+[[answer]]
+day = 1
+input = \"day_01.txt\"
+part1 = 72511
+
+[[answer]]
+day = 7
+input = \"day_07.txt\"
+part1 = 1
+";
+
+    #[test]
+    fn test_extract() {
+        let page = extract(PAGE);
+
+        assert_eq!(
+            page.blocks,
+            ["    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\n", "[D]\n"]
+        );
+        assert_eq!(page.part1, Some(Expected::Text("CMZ".into())));
+        assert_eq!(page.part2, Some(Expected::Text("MCD".into())));
+
+        let part1_only = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        let page = extract(part1_only);
+        assert_eq!(page.blocks.len(), 2);
+        assert_eq!(page.part2, None);
+    }
+
+    #[test]
+    fn test_record_answers() {
+        let page = Page {
+            blocks: vec![],
+            part1: Some(Expected::Integer(24000)),
+            part2: Some(Expected::Text("a \"b\"".into())),
+        };
+
+        let entry = "[[answer]]\nday = 5\ninput = \"examples/day_05.txt\"\npart1 = 24000\npart2 = \"a \\\"b\\\"\"\n";
+        let recorded = record_answers(ANSWERS, 5, "examples/day_05.txt", &page, false).unwrap();
+        assert_eq!(
+            recorded,
+            ANSWERS.replace(
+                "[[answer]]\nday = 7",
+                &format!("{entry}\n[[answer]]\nday = 7")
+            )
+        );
+
+        let err = record_answers(&recorded, 5, "examples/day_05.txt", &page, false).unwrap_err();
+        assert!(err.contains("--force"));
+
+        let page = Page {
+            part2: None,
+            ..page
+        };
+        let replaced = record_answers(&recorded, 5, "examples/day_05.txt", &page, true).unwrap();
+        assert_eq!(replaced, recorded.replace("part2 = \"a \\\"b\\\"\"\n", ""));
+
+        let appended = record_answers(ANSWERS, 9, "examples/day_09.txt", &page, false).unwrap();
+        assert!(appended.ends_with(
+            "part1 = 1\n\n[[answer]]\nday = 9\ninput = \"examples/day_09.txt\"\npart1 = 24000\n"
+        ));
+    }
+
+    #[test]
+    fn test_save() {
+        let root = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(root.join("inputs/examples")).unwrap();
+        fs::write(root.join("answers.toml"), ANSWERS).unwrap();
+        // As left behind by `aoc new`.
+        fs::write(root.join("inputs/examples/day_05.txt"), "").unwrap();
+
+        let page = extract(PAGE);
+        let written = save(&root, 5, &page.blocks[0], &page, false).unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/examples/day_05.txt")).unwrap(),
+            page.blocks[0]
+        );
+
+        let err = save(&root, 5, &page.blocks[1], &page, false).unwrap_err();
+        assert!(err.contains("already has a different example"));
+        let err = save(&root, 5, &page.blocks[0], &page, false).unwrap_err();
+        assert!(err.contains("already has answers"));
+        save(&root, 5, &page.blocks[1], &page, true).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
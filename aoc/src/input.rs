@@ -0,0 +1,91 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use utils::input::{HttpFetcher, InputCache, InputError, DEFAULT_BASE_URL};
+
+/// Directory, relative to the working directory, where each day's input is looked up by default.
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Where the session token is read from if `AOC_SESSION` isn't set. `.aoc/` is never committed.
+pub const SESSION_FILE: &str = ".aoc/session";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/day_XX.txt` for the day being run.
+    Default,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.into()),
+        }
+    }
+
+    /// Where the input for the day is read from, for reporting alongside the answers.
+    pub fn describe(&self, day: u8) -> String {
+        match self {
+            InputSource::Default => cache().path(day).display().to_string(),
+            InputSource::Path(path) => path.display().to_string(),
+            InputSource::Stdin => "-".into(),
+        }
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, String> {
+        match self {
+            InputSource::Default => cache().get(day).map_err(|err| match err {
+                InputError::Missing { .. } => {
+                    format!("{err} (run `aoc fetch {day}` to download it)")
+                }
+                err => err.to_string(),
+            }),
+            InputSource::Path(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| format!("Could not read input from stdin: {err}"))?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn cache() -> InputCache {
+    InputCache::new(DEFAULT_DIR)
+}
+
+/// The session token from `AOC_SESSION` or `.aoc/session`, needed to fetch inputs and submit
+/// answers.
+pub fn session() -> Result<String, String> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(SESSION_FILE).map_err(|_| {
+            format!("No session token found: set AOC_SESSION or save it to {SESSION_FILE}")
+        })?,
+    };
+
+    Ok(session.trim().to_owned())
+}
+
+/// The site to talk to, which `AOC_BASE_URL` overrides.
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.into())
+}
+
+pub fn fetcher() -> Result<HttpFetcher, String> {
+    Ok(HttpFetcher::new(base_url(), session()?))
+}
+
+pub fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|err| format!("Could not read input from {}: {err}", path.display()))
+}
@@ -0,0 +1,9 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod examples;
+pub mod input;
+pub mod output;
+pub mod parallel;
+pub mod scaffold;
+pub mod submit;
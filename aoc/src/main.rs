@@ -0,0 +1,444 @@
+use std::{
+    env, fs,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    answers::{self, Answers},
+    bench::{self, BenchRun, History},
+    days, examples,
+    input::{self, InputSource},
+    output::Format,
+    parallel::{self, DayError, DayOutcome},
+    scaffold,
+    submit::{self, HttpSubmitter, Refusal, Submissions, Submitter, Verdict},
+};
+use cli::Command;
+use day_01::Width;
+use utils::{trace, Answer, Params, Part, Puzzle, SolveError};
+
+mod cli;
+
+fn main() -> ExitCode {
+    let command = match cli::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{err}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Run {
+            day,
+            parts,
+            input,
+            format,
+            jobs,
+            stats,
+            width,
+            params,
+        } => days::select(day)
+            .and_then(|puzzles| days::with_width(puzzles, width))
+            .and_then(|puzzles| run(&puzzles, &parts, &input, format, jobs, stats, params)),
+        Command::Bench {
+            day,
+            parts,
+            iterations,
+            threshold,
+            history,
+            save,
+        } => run_bench(day, &parts, iterations, threshold, &history, save),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part } => submit(day, part),
+        Command::New { day } => new_day(day),
+        Command::Example {
+            day,
+            page,
+            block,
+            force,
+        } => example(day, &page, block, force),
+        Command::Report { day, input, width } => report(day, &input, width),
+        Command::Help => {
+            print!("{}", cli::USAGE);
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
+    puzzles: &[&dyn Puzzle],
+    parts: &[Part],
+    input: &InputSource,
+    format: Format,
+    jobs: Option<usize>,
+    stats: bool,
+    params: Params,
+) -> Result<(), String> {
+    let params = input_params(puzzles, input, params)?;
+    if stats && !trace::ENABLED {
+        eprintln!("Nothing is recorded for --stats unless built with `--features trace`");
+    }
+
+    let start = Instant::now();
+    let outcomes = parallel::run_days(
+        puzzles,
+        parts,
+        input,
+        &params,
+        jobs.unwrap_or(1),
+        |outcome| match &outcome.result {
+            Ok(run) => {
+                let source = input.describe(outcome.day);
+                for part in &run.parts {
+                    let line = format.format(outcome.day, part, &source, stats);
+                    match (&part.answer, format) {
+                        (Err(_), Format::Human) => eprintln!("{line}"),
+                        _ => println!("{line}"),
+                    }
+                }
+            }
+            Err(err) => eprintln!("Day {:02}: {err}", outcome.day),
+        },
+    );
+    let wall = start.elapsed();
+
+    let failed = outcomes.iter().filter(|o| !solved(o)).count();
+    let mut wrong = 0;
+
+    // The summary is only wanted alongside --jobs, and would get in the way of JSON output.
+    if let (Some(jobs), Format::Human) = (jobs, format) {
+        // Known answers only apply to the default inputs they were recorded for.
+        let known = match input {
+            InputSource::Default => Answers::load(Path::new(answers::DEFAULT_PATH)).ok(),
+            _ => None,
+        };
+        wrong = print_summary(&outcomes, known.as_ref(), wall, jobs);
+    }
+
+    match (failed, wrong) {
+        (0, 0) => Ok(()),
+        (0, wrong) => Err(format!(
+            "{wrong} answer(s) did not match {}",
+            answers::DEFAULT_PATH
+        )),
+        (failed, _) => Err(format!("{failed} day(s) could not be solved")),
+    }
+}
+
+/// The values to run `puzzles` with: any recorded in `answers.toml` for the input, overridden by
+/// those given on the command line.
+fn input_params(
+    puzzles: &[&dyn Puzzle],
+    input: &InputSource,
+    given: Params,
+) -> Result<Params, String> {
+    let mut params = match (input, puzzles) {
+        (InputSource::Path(path), [puzzle]) => Answers::load(Path::new(answers::DEFAULT_PATH))
+            .ok()
+            .and_then(|answers| Some(answers.find_path(puzzle.day(), path)?.params()))
+            .unwrap_or_default(),
+        _ => Params::default(),
+    };
+    params.extend(given);
+
+    for puzzle in puzzles {
+        params
+            .check(puzzle.params())
+            .map_err(|err| format!("Day {:02} {err}", puzzle.day()))?;
+    }
+
+    Ok(params)
+}
+
+/// Whether a day ran and every part it was asked for produced an answer.
+fn solved(outcome: &DayOutcome) -> bool {
+    match &outcome.result {
+        Ok(run) => run.parts.iter().all(|part| part.answer.is_ok()),
+        Err(_) => false,
+    }
+}
+
+/// Prints a table of how every day went, returning the number of answers that didn't match the
+/// known ones.
+fn print_summary(
+    outcomes: &[DayOutcome],
+    known: Option<&Answers>,
+    wall: Duration,
+    jobs: usize,
+) -> usize {
+    let mut wrong = 0;
+
+    println!();
+    println!(
+        "{:<4} {:<7} {:<7} {:>10}  Status",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+
+    for outcome in outcomes {
+        let entry = known
+            .and_then(|answers| answers.find(outcome.day, &format!("day_{:02}.txt", outcome.day)));
+
+        let check = |part: Part| {
+            let Ok(run) = &outcome.result else {
+                return "-";
+            };
+            let Some(answer) = run.parts.iter().find(|r| r.part == part) else {
+                return "-";
+            };
+
+            let Ok(answer) = &answer.answer else {
+                return "error";
+            };
+
+            match entry.and_then(|entry| entry.expected(part)) {
+                Some(expected) if expected.matches(answer) => "pass",
+                Some(_) => "FAIL",
+                None => "?",
+            }
+        };
+        let (part1, part2) = (check(Part::One), check(Part::Two));
+        wrong += [part1, part2].iter().filter(|c| **c == "FAIL").count();
+
+        let status = match &outcome.result {
+            Ok(_) if solved(outcome) => "ok",
+            Ok(_) => "failed",
+            Err(DayError::Input(_)) => "no input",
+            Err(DayError::Solve(SolveError::Parse(_))) => "invalid input",
+            Err(DayError::Solve(SolveError::Overflow(_))) => "overflow",
+            Err(DayError::Solve(_)) => "failed",
+            Err(DayError::Panic(_)) => "panicked",
+        };
+
+        println!(
+            "{:<4} {:<7} {:<7} {:>10}  {status}",
+            format!("{:02}", outcome.day),
+            part1,
+            part2,
+            bench::format_nanos(outcome.wall.as_nanos() as u64),
+        );
+    }
+
+    let total = outcomes.iter().map(|o| o.wall).sum::<Duration>();
+    println!(
+        "\nWall time {} for {} of work on {jobs} job(s)",
+        bench::format_nanos(wall.as_nanos() as u64),
+        bench::format_nanos(total.as_nanos() as u64),
+    );
+
+    wrong
+}
+
+fn run_bench(
+    day: Option<u8>,
+    parts: &[Part],
+    iterations: usize,
+    threshold: f64,
+    history_path: &Path,
+    save: bool,
+) -> Result<(), String> {
+    let mut history = History::load(history_path)?;
+    let mut results = vec![];
+    let mut regressions = vec![];
+
+    println!(
+        "{:<4} {:<7} {:>10} {:>10} {:>10} {:>9}",
+        "Day", "Step", "Min", "Median", "Max", "Change"
+    );
+
+    let mut failed = 0;
+
+    for puzzle in days::select(day)? {
+        let result = match bench::bench_day(puzzle, &InputSource::Default, parts, iterations) {
+            Ok(result) => result,
+            Err(err) => {
+                // The other days are still timed, and saved below.
+                println!("{:02}   {err}", puzzle.day());
+                failed += 1;
+                continue;
+            }
+        };
+        let previous = history.latest(result.day).map(|d| d.steps());
+        let day_regressions = history.regressions(&result, threshold);
+
+        for (step, stats) in result.steps() {
+            let change = previous
+                .as_ref()
+                .and_then(|steps| steps.iter().find(|(s, _)| *s == step))
+                .map(|(_, prev)| bench::percent_change(prev.median_ns, stats.median_ns))
+                .map(|change| format!("{change:+.1}%"))
+                .unwrap_or_default();
+            let flag = match day_regressions.iter().any(|r| r.step == step) {
+                true => " !",
+                false => "",
+            };
+
+            println!(
+                "{:<4} {:<7} {:>10} {:>10} {:>10} {:>9}{flag}",
+                format!("{:02}", result.day),
+                step,
+                bench::format_nanos(stats.min_ns),
+                bench::format_nanos(stats.median_ns),
+                bench::format_nanos(stats.max_ns),
+                change,
+            );
+        }
+
+        regressions.extend(day_regressions);
+        results.push(result);
+    }
+
+    if save {
+        history.runs.push(BenchRun::new(iterations, results));
+        history.save(history_path)?;
+    }
+
+    match (failed, regressions.len()) {
+        (0, 0) => Ok(()),
+        (0, count) => Err(format!(
+            "{count} step(s) regressed by more than {threshold}% against the previous run"
+        )),
+        (failed, _) => Err(format!("{failed} day(s) could not be benchmarked")),
+    }
+}
+
+fn fetch(day: Option<u8>) -> Result<(), String> {
+    let cache = input::cache();
+    let mut fetcher = None;
+
+    // A single day can be fetched before it has been solved.
+    let days = match day {
+        Some(day) => vec![day],
+        None => days::DAYS.iter().map(|puzzle| puzzle.day()).collect(),
+    };
+
+    for day in days {
+        if cache.contains(day) {
+            println!("Day {day:02}: already in {}", cache.path(day).display());
+            continue;
+        }
+
+        // Only look for a session token once something actually needs downloading.
+        let fetcher = match &fetcher {
+            Some(fetcher) => fetcher,
+            None => fetcher.insert(input::fetcher()?),
+        };
+
+        cache
+            .get_or_fetch(day, fetcher)
+            .map_err(|err| err.to_string())?;
+        println!("Day {day:02}: saved to {}", cache.path(day).display());
+    }
+
+    Ok(())
+}
+
+fn submit(day: u8, part: Part) -> Result<(), String> {
+    let puzzle = days::select(Some(day))?[0];
+    let data = InputSource::Default.load(day)?;
+    let run = puzzle
+        .run(&data, &[part])
+        .map_err(|err| format!("Day {day:02}: {err}"))?;
+    let answer = run.parts[0]
+        .answer
+        .as_ref()
+        .map_err(|err| format!("Day {day:02} part {part}: {err}"))?;
+    println!("{}", Format::Human.format(day, &run.parts[0], "", false));
+
+    if let Answer::Grid(_) = answer {
+        return Err(
+            "Pictures can't be submitted, read the letters off and submit them by hand".into(),
+        );
+    }
+
+    let path = Path::new(submit::DEFAULT_STORE);
+    let mut store = Submissions::load(path)?;
+    match store.check(day, part, answer) {
+        Ok(()) => {}
+        Err(Refusal::AlreadyAccepted) => {
+            println!("Already accepted, not submitting it again");
+            return Ok(());
+        }
+        Err(refusal) => return Err(format!("Not submitting: {refusal}")),
+    }
+
+    let submitter = HttpSubmitter::new(input::base_url(), input::session()?);
+    let verdict = submitter.submit(day, part, &answer.to_string())?;
+    store.record(day, part, answer, &verdict);
+    store.save(path)?;
+
+    match verdict {
+        Verdict::Correct => {
+            println!("{verdict}");
+            Ok(())
+        }
+        verdict => Err(verdict.to_string()),
+    }
+}
+
+fn new_day(day: u8) -> Result<(), String> {
+    let root = env::current_dir().map_err(|err| format!("Could not find the workspace: {err}"))?;
+
+    for path in scaffold::new_day(&root, day)? {
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn example(day: u8, page: &Path, block: usize, force: bool) -> Result<(), String> {
+    let html = fs::read_to_string(page)
+        .map_err(|err| format!("Could not read {}: {err}", page.display()))?;
+    let page = examples::extract(&html);
+    let example = match page.blocks.get(block - 1) {
+        Some(example) => example,
+        None => {
+            return Err(format!(
+                "Asked for code block {block}, but the page only has {}",
+                page.blocks.len()
+            ))
+        }
+    };
+
+    let root = env::current_dir().map_err(|err| format!("Could not find the workspace: {err}"))?;
+    for path in examples::save(&root, day, example, &page, force)? {
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("Wrote {}", path.display());
+    }
+
+    for part in Part::ALL {
+        match page.expected(part) {
+            Some(expected) => println!("Part {part}: {expected}"),
+            None => println!("Part {part}: no answer found"),
+        }
+    }
+
+    Ok(())
+}
+
+fn report(day: u8, input: &InputSource, width: Option<Width>) -> Result<(), String> {
+    let puzzle = days::with_width(days::select(Some(day))?, width)?[0];
+    let data = input.load(day)?;
+
+    match puzzle.report(&data) {
+        Ok(Some(report)) => {
+            println!("{}", report.trim_end());
+            Ok(())
+        }
+        Ok(None) => Err(format!(
+            "Day {day:02} has nothing to report beyond its answers"
+        )),
+        Err(err) => Err(format!("Day {day:02}: {err}")),
+    }
+}
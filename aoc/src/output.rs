@@ -0,0 +1,238 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use utils::{trace::Stats, Answer, Part, PartRun};
+
+use crate::bench;
+
+/// How `aoc run` prints answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `Day XX part N: answer`, with pictures starting on their own line.
+    Human,
+    /// One JSON object per answer and line, for scripts.
+    Json,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        match arg {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {arg} (expected human or json)")),
+        }
+    }
+
+    /// Formats a part's answer, followed by what it recorded through `utils::trace` if `stats`
+    /// is set.
+    pub fn format(&self, day: u8, run: &PartRun, input: &str, stats: bool) -> String {
+        match self {
+            Format::Human => {
+                let mut text = match &run.answer {
+                    Ok(answer) => human(day, run.part, answer),
+                    Err(err) => format!("Day {day:02} part {}: {err}", run.part),
+                };
+                if stats {
+                    for line in human_stats(&run.stats) {
+                        text += &format!("\n    {line}");
+                    }
+                }
+                text
+            }
+            Format::Json => {
+                let mut record = Record::new(day, run, input);
+                record.stats = stats.then(|| json_stats(&run.stats));
+                serde_json::to_string(&record).expect("Records are always serializable")
+            }
+        }
+    }
+}
+
+fn human(day: u8, part: Part, answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(_) => format!("Day {day:02} part {part}:\n{answer}"),
+        _ => format!("Day {day:02} part {part}: {answer}"),
+    }
+}
+
+fn human_stats(stats: &Stats) -> Vec<String> {
+    let counters = stats
+        .counters
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"));
+    let spans = stats.spans.iter().map(|(name, span)| {
+        let calls = match span.calls {
+            1 => "1 call".to_owned(),
+            calls => format!("{calls} calls"),
+        };
+        let total = bench::format_nanos(span.total.as_nanos() as u64);
+        format!("{name}: {calls}, {total}")
+    });
+    let events = stats
+        .events
+        .iter()
+        .map(|(name, message)| format!("[{name}] {message}"));
+    let dropped =
+        (stats.dropped_events > 0).then(|| format!("... and {} more events", stats.dropped_events));
+
+    counters.chain(spans).chain(events).chain(dropped).collect()
+}
+
+fn json_stats(stats: &Stats) -> Value {
+    let counters = stats
+        .counters
+        .iter()
+        .map(|(name, value)| (name.to_string(), json!(value)));
+    let spans = stats.spans.iter().map(|(name, span)| {
+        let span = json!({ "calls": span.calls, "total_ns": span.total.as_nanos() as u64 });
+        (name.to_string(), span)
+    });
+    let events = stats
+        .events
+        .iter()
+        .map(|(name, message)| json!({ "name": name, "message": message }))
+        .collect::<Vec<_>>();
+
+    json!({
+        "counters": counters.collect::<serde_json::Map<_, _>>(),
+        "spans": spans.collect::<serde_json::Map<_, _>>(),
+        "events": events,
+        "dropped_events": stats.dropped_events,
+    })
+}
+
+/// A single answer as printed by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    /// `null` when the part failed, in which case `error` says why.
+    pub answer: Option<JsonAnswer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ns: u64,
+    /// Where the input was read from, `-` being stdin.
+    pub input: &'a str,
+    /// Only included with `--stats`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Value>,
+}
+
+/// Integers stay numbers, everything else is written as it would be displayed.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum JsonAnswer {
+    Integer(i128),
+    Text(String),
+}
+
+impl<'a> Record<'a> {
+    pub fn new(day: u8, run: &PartRun, input: &'a str) -> Self {
+        Record {
+            day,
+            part: run.part.number(),
+            answer: match &run.answer {
+                Ok(Answer::Integer(val)) => Some(JsonAnswer::Integer(*val)),
+                Ok(answer) => Some(JsonAnswer::Text(answer.to_string())),
+                Err(_) => None,
+            },
+            error: run.answer.as_ref().err().map(|err| err.to_string()),
+            duration_ns: run.duration.as_nanos() as u64,
+            input,
+            stats: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use utils::{
+        trace::{SpanStats, Stats},
+        Answer, Part, PartRun, SolveError,
+    };
+
+    use super::Format;
+
+    fn run(part: Part, answer: Result<Answer, SolveError>) -> PartRun {
+        PartRun {
+            part,
+            answer,
+            duration: Duration::from_nanos(1500),
+            stats: Stats::default(),
+        }
+    }
+
+    #[test]
+    fn test_human() {
+        let integer = run(Part::One, Ok(Answer::Integer(24000)));
+        assert_eq!(
+            Format::Human.format(1, &integer, "inputs/day_01.txt", false),
+            "Day 01 part 1: 24000"
+        );
+
+        let grid = run(Part::Two, Ok(Answer::Grid(vec!["#.".into(), ".#".into()])));
+        assert_eq!(
+            Format::Human.format(10, &grid, "-", false),
+            "Day 10 part 2:\n#.\n.#"
+        );
+
+        let failed = run(Part::One, Err(SolveError::no_solution("no gap found")));
+        assert_eq!(
+            Format::Human.format(15, &failed, "-", false),
+            "Day 15 part 1: no solution: no gap found"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let integer = run(Part::One, Ok(Answer::Integer(24000)));
+        assert_eq!(
+            Format::Json.format(1, &integer, "inputs/day_01.txt", false),
+            r#"{"day":1,"part":1,"answer":24000,"duration_ns":1500,"input":"inputs/day_01.txt"}"#
+        );
+
+        let grid = run(Part::Two, Ok(Answer::Grid(vec!["#.".into(), ".#".into()])));
+        assert_eq!(
+            Format::Json.format(10, &grid, "-", false),
+            r##"{"day":10,"part":2,"answer":"#.\n.#","duration_ns":1500,"input":"-"}"##
+        );
+
+        let failed = run(Part::One, Err(SolveError::no_solution("no gap found")));
+        assert_eq!(
+            Format::Json.format(15, &failed, "-", false),
+            r#"{"day":15,"part":1,"answer":null,"error":"no solution: no gap found","duration_ns":1500,"input":"-"}"#
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut traced = run(Part::One, Ok(Answer::Integer(31)));
+        traced.stats = Stats {
+            counters: vec![("bfs nodes expanded", 120)],
+            spans: vec![(
+                "pairing paths",
+                SpanStats {
+                    calls: 1,
+                    total: Duration::from_micros(2500),
+                },
+            )],
+            events: vec![("new best", "1651 released".into())],
+            dropped_events: 3,
+        };
+
+        assert_eq!(
+            Format::Human.format(12, &traced, "-", true),
+            "Day 12 part 1: 31\n    bfs nodes expanded: 120\n    pairing paths: 1 call, 2.50ms\n    \
+             [new best] 1651 released\n    ... and 3 more events"
+        );
+        assert_eq!(
+            Format::Human.format(12, &traced, "-", false),
+            "Day 12 part 1: 31"
+        );
+        assert_eq!(
+            Format::Json.format(12, &traced, "-", true),
+            r#"{"day":12,"part":1,"answer":31,"duration_ns":1500,"input":"-","stats":{"counters":{"bfs nodes expanded":120},"dropped_events":3,"events":[{"message":"1651 released","name":"new best"}],"spans":{"pairing paths":{"calls":1,"total_ns":2500000}}}}"#
+        );
+    }
+}
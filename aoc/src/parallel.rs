@@ -0,0 +1,182 @@
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use utils::{Params, Part, Puzzle, Run, SolveError};
+
+use crate::input::InputSource;
+
+/// Everything that happened when running a single day.
+#[derive(Debug)]
+pub struct DayOutcome {
+    pub day: u8,
+    pub result: Result<Run, DayError>,
+    /// Time from starting to load the input to having every answer.
+    pub wall: Duration,
+}
+
+/// Why a day produced no answers.
+#[derive(Debug)]
+pub enum DayError {
+    Input(String),
+    Solve(SolveError),
+    /// The solution panicked, with the panic's message if it had one.
+    Panic(String),
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::Input(err) => write!(f, "{err}"),
+            DayError::Solve(err) => write!(f, "{err}"),
+            DayError::Panic(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// Runs each day on up to `jobs` threads at once, so a slow day doesn't hold up the others and a
+/// panicking one doesn't stop them. `on_done` is called with each outcome as soon as it's ready,
+/// one at a time; the outcomes are also returned in the order of `puzzles`.
+pub fn run_days<F>(
+    puzzles: &[&dyn Puzzle],
+    parts: &[Part],
+    input: &InputSource,
+    params: &Params,
+    jobs: usize,
+    on_done: F,
+) -> Vec<DayOutcome>
+where
+    F: FnMut(&DayOutcome) + Send,
+{
+    let next = AtomicUsize::new(0);
+    let on_done = Mutex::new(on_done);
+    let outcomes = Mutex::new(vec![]);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, puzzles.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(puzzle) = puzzles.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcome = run_day(*puzzle, parts, input, params);
+                    (on_done.lock().unwrap())(&outcome);
+                    outcomes.lock().unwrap().push(outcome);
+                }
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|outcome| {
+        puzzles
+            .iter()
+            .position(|puzzle| puzzle.day() == outcome.day)
+    });
+    outcomes
+}
+
+fn run_day(
+    puzzle: &dyn Puzzle,
+    parts: &[Part],
+    input: &InputSource,
+    params: &Params,
+) -> DayOutcome {
+    let start = Instant::now();
+
+    let result = match input.load(puzzle.day()) {
+        Ok(data) => {
+            match panic::catch_unwind(AssertUnwindSafe(|| puzzle.run_with(&data, parts, params))) {
+                Ok(run) => run.map_err(DayError::Solve),
+                Err(payload) => Err(DayError::Panic(panic_message(payload.as_ref()))),
+            }
+        }
+        Err(err) => Err(DayError::Input(err)),
+    };
+
+    DayOutcome {
+        day: puzzle.day(),
+        result,
+        wall: start.elapsed(),
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "no message".into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use utils::{Answer, Params, ParseError, Part, Puzzle, Solution, SolveError};
+
+    use super::{run_days, DayError};
+    use crate::input::InputSource;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        const DAY: u8 = 1;
+
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<u32, ParseError> {
+            utils::parse_value(input.trim(), "a number")
+        }
+
+        fn part1(input: &u32) -> Result<Answer, SolveError> {
+            Ok((input * 2).into())
+        }
+
+        fn part2(_: &u32) -> Result<Answer, SolveError> {
+            panic!("part 2 is not done")
+        }
+    }
+
+    #[test]
+    fn test_panics_are_isolated() {
+        let path = std::env::temp_dir().join(format!("aoc-parallel-{}.txt", std::process::id()));
+        std::fs::write(&path, "21\n").unwrap();
+
+        let puzzles: Vec<&dyn Puzzle> = vec![&Doubler; 3];
+        let finished = Mutex::new(0);
+        let outcomes = run_days(
+            &puzzles,
+            &[Part::One],
+            &InputSource::Path(path.clone()),
+            &Params::default(),
+            2,
+            |_| *finished.lock().unwrap() += 1,
+        );
+        assert_eq!(*finished.lock().unwrap(), 3);
+        assert!(outcomes
+            .iter()
+            .all(|o| o.result.as_ref().unwrap().parts[0].answer == Ok(Answer::Integer(42))));
+
+        let outcomes = run_days(
+            &puzzles[..1],
+            &Part::ALL,
+            &InputSource::Path(path.clone()),
+            &Params::default(),
+            4,
+            |_| {},
+        );
+        assert!(matches!(
+            &outcomes[0].result,
+            Err(DayError::Panic(message)) if message == "part 2 is not done"
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::input;
+
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+
+/// The runner's manifest and registry, relative to the workspace root.
+const RUNNER_MANIFEST: &str = "aoc/Cargo.toml";
+const RUNNER_REGISTRY: &str = "aoc/src/days.rs";
+
+/// Creates `days/day_XX` from the template under the workspace `root`, along with empty input
+/// files, and registers it with the runner. Returns every file that was created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join("days").join(format!("day_{day:02}"));
+    if crate_dir.exists() {
+        return Err(format!(
+            "Day {day:02} already exists at {}",
+            crate_dir.display()
+        ));
+    }
+
+    // Work out both registry changes before writing anything, so a failure leaves the tree as
+    // it was.
+    let manifest_path = root.join(RUNNER_MANIFEST);
+    let manifest = register_dependency(&read(&manifest_path)?, day).ok_or(format!(
+        "Could not register day {day:02} in {RUNNER_MANIFEST}"
+    ))?;
+
+    let registry_path = root.join(RUNNER_REGISTRY);
+    let registry = register_puzzle(&read(&registry_path)?, day).ok_or(format!(
+        "Could not register day {day:02} in {RUNNER_REGISTRY}"
+    ))?;
+
+    let inputs = root.join(input::DEFAULT_DIR);
+    let mut files = vec![
+        (crate_dir.join("Cargo.toml"), render(MANIFEST_TEMPLATE, day)),
+        (
+            crate_dir.join("src").join("lib.rs"),
+            render(LIB_TEMPLATE, day),
+        ),
+        (manifest_path, manifest),
+        (registry_path, registry),
+    ];
+
+    // Inputs that are already there, e.g. from fetching them early, are kept.
+    for path in [
+        inputs.join(format!("day_{day:02}.txt")),
+        inputs.join("examples").join(format!("day_{day:02}.txt")),
+    ] {
+        if !path.exists() {
+            files.push((path, String::new()));
+        }
+    }
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Could not create {}: {err}", parent.display()))?;
+        }
+
+        fs::write(path, contents)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Could not read {}: {err}", path.display()))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{DAY_PADDED}}", &format!("{day:02}"))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Adds the day's crate to the runner's dependencies, keeping the days in order.
+fn register_dependency(manifest: &str, day: u8) -> Option<String> {
+    let entry = format!("day_{day:02} = {{ path = \"../days/day_{day:02}\" }}");
+
+    insert_entry(
+        manifest,
+        &entry,
+        day,
+        |line| line.strip_prefix("day_"),
+        |line| line.starts_with("utils = "),
+    )
+}
+
+/// Adds the day to the list of puzzles the runner knows about, keeping the days in order.
+fn register_puzzle(registry: &str, day: u8) -> Option<String> {
+    let entry = format!("    &day_{day:02}::Day{day:02},");
+
+    insert_entry(
+        registry,
+        &entry,
+        day,
+        |line| line.trim().strip_prefix("&day_"),
+        |line| line.starts_with("pub static DAYS"),
+    )
+}
+
+/// Inserts `entry` after the last line registering an earlier day, or after the `anchor` line if
+/// there are none. `registered` strips a line down to the day number it starts with, if any.
+/// Returns `None` if the day is already registered or there is nowhere to put it.
+fn insert_entry(
+    text: &str,
+    entry: &str,
+    day: u8,
+    registered: impl Fn(&str) -> Option<&str>,
+    anchor: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let mut position = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let existing = registered(line)
+            .and_then(|rest| rest.get(..2))
+            .and_then(|number| number.parse::<u8>().ok());
+
+        match existing {
+            Some(existing) if existing == day => return None,
+            Some(existing) if existing < day => position = Some(idx + 1),
+            Some(_) => {}
+            None if position.is_none() && anchor(line) => position = Some(idx + 1),
+            None => {}
+        }
+    }
+
+    lines.insert(position?, entry);
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{new_day, register_dependency, register_puzzle};
+
+    const MANIFEST: &str = "\
+[dependencies]
+utils = { path = \"../lib/utils\" }
+day_01 = { path = \"../days/day_01\" }
+day_03 = { path = \"../days/day_03\" }
+";
+
+    const REGISTRY: &str = "\
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day_01::Day01,
+    &day_03::Day03,
+];
+";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register_dependency(MANIFEST, 2).unwrap(),
+            MANIFEST.replace(
+                "day_03 =",
+                "day_02 = { path = \"../days/day_02\" }\nday_03 ="
+            )
+        );
+        assert_eq!(
+            register_puzzle(REGISTRY, 25).unwrap(),
+            REGISTRY.replace("Day03,\n", "Day03,\n    &day_25::Day25,\n")
+        );
+        assert_eq!(register_puzzle(REGISTRY, 3), None);
+
+        let empty = "pub static DAYS: &[&dyn Puzzle] = &[\n];\n";
+        assert_eq!(
+            register_puzzle(empty, 1).unwrap(),
+            "pub static DAYS: &[&dyn Puzzle] = &[\n    &day_01::Day01,\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), REGISTRY).unwrap();
+
+        let created = new_day(&root, 7).unwrap();
+        assert_eq!(created.len(), 6);
+
+        let lib = fs::read_to_string(root.join("days/day_07/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(lib.contains("Err(SolveError::no_solution(\"not solved yet\"))"));
+        assert!(!lib.contains("todo!()"));
+        assert!(root.join("inputs/examples/day_07.txt").exists());
+
+        let registry = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(registry.contains("    &day_07::Day07,\n];"));
+
+        let err = new_day(&root, 7).unwrap_err();
+        assert!(err.contains("already exists"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
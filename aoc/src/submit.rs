@@ -0,0 +1,389 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use utils::{input::YEAR, Answer, Part};
+
+/// Where every submitted answer and the site's response to it are kept, relative to the working
+/// directory.
+pub const DEFAULT_STORE: &str = ".aoc/submissions.json";
+
+/// Somewhere answers can be sent to be checked.
+pub trait Submitter {
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, String>;
+}
+
+/// The site's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Another answer was submitted too recently. Holds how long is left to wait, if given.
+    TooRecent(Option<String>),
+    /// The part is locked, or has already been solved.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Interprets the page returned after submitting an answer.
+    pub fn from_response(body: &str) -> Result<Verdict, String> {
+        if body.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if body.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_owned());
+            Ok(Verdict::TooRecent(wait))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err("the response didn't say whether the answer was right".into())
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::Wrong => write!(f, "That's not the right answer"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            Verdict::TooLow => write!(f, "That's not the right answer, it's too low"),
+            Verdict::TooRecent(Some(wait)) => {
+                write!(
+                    f,
+                    "An answer was submitted too recently, {wait} left to wait"
+                )
+            }
+            Verdict::TooRecent(None) => write!(f, "An answer was submitted too recently"),
+            Verdict::WrongLevel => write!(f, "That part is locked or has already been solved"),
+        }
+    }
+}
+
+/// Submits answers over HTTP using a logged in session.
+#[derive(Debug)]
+pub struct HttpSubmitter {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpSubmitter {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        HttpSubmitter {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let body = self
+            .agent
+            .post(&format!("{}/{YEAR}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => format!("the server responded with {status}"),
+                ureq::Error::Transport(err) => err.to_string(),
+            })?
+            .into_string()
+            .map_err(|err| format!("could not read the response: {err}"))?;
+
+        Verdict::from_response(&body)
+    }
+}
+
+/// What is known about the answer to one part from earlier submissions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartSubmissions {
+    pub day: u8,
+    pub part: u8,
+    pub correct: Option<String>,
+    pub wrong: Vec<String>,
+    /// The smallest answer known to be too high.
+    pub too_high: Option<i128>,
+    /// The largest answer known to be too low.
+    pub too_low: Option<i128>,
+}
+
+/// Every answer submitted so far.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Submissions {
+    pub parts: Vec<PartSubmissions>,
+}
+
+/// Why an answer shouldn't be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyAccepted,
+    /// A different answer has already been accepted.
+    DiffersFromAccepted(String),
+    KnownWrong,
+    TooHigh(i128),
+    TooLow(i128),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyAccepted => write!(f, "this answer was already accepted"),
+            Refusal::DiffersFromAccepted(correct) => {
+                write!(f, "{correct} was already accepted as the answer")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already rejected"),
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+impl Submissions {
+    /// Loads the store from `path`, starting afresh if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Submissions, String> {
+        if !path.exists() {
+            return Ok(Submissions::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+
+        serde_json::from_str(&contents).map_err(|err| format!("Invalid {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Could not create {}: {err}", parent.display()))?;
+        }
+
+        let contents =
+            serde_json::to_string_pretty(self).expect("Submissions are always serializable");
+        fs::write(path, contents)
+            .map_err(|err| format!("Could not write {}: {err}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&PartSubmissions> {
+        self.parts
+            .iter()
+            .find(|p| p.day == day && p.part == part.number())
+    }
+
+    /// Checks an answer against everything learned from earlier submissions of the same part.
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Result<(), Refusal> {
+        let Some(known) = self.get(day, part) else {
+            return Ok(());
+        };
+        let text = answer.to_string();
+
+        match &known.correct {
+            Some(correct) if *correct == text => return Err(Refusal::AlreadyAccepted),
+            Some(correct) => return Err(Refusal::DiffersFromAccepted(correct.clone())),
+            None => {}
+        }
+
+        if known.wrong.contains(&text) {
+            return Err(Refusal::KnownWrong);
+        }
+
+        if let Answer::Integer(value) = answer {
+            match (known.too_high, known.too_low) {
+                (Some(bound), _) if *value >= bound => return Err(Refusal::TooHigh(bound)),
+                (_, Some(bound)) if *value <= bound => return Err(Refusal::TooLow(bound)),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remembers the site's response to an answer.
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer, verdict: &Verdict) {
+        let known = match self
+            .parts
+            .iter()
+            .position(|p| p.day == day && p.part == part.number())
+        {
+            Some(idx) => &mut self.parts[idx],
+            None => {
+                self.parts.push(PartSubmissions {
+                    day,
+                    part: part.number(),
+                    ..Default::default()
+                });
+                self.parts.last_mut().unwrap()
+            }
+        };
+        let text = answer.to_string();
+        let value = match answer {
+            Answer::Integer(value) => Some(*value),
+            _ => None,
+        };
+
+        match verdict {
+            Verdict::Correct => known.correct = Some(text),
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => {
+                if !known.wrong.contains(&text) {
+                    known.wrong.push(text);
+                }
+            }
+            // Nothing was learned about the answer itself.
+            Verdict::TooRecent(_) | Verdict::WrongLevel => {}
+        }
+
+        match (verdict, value) {
+            (Verdict::TooHigh, Some(value)) => {
+                known.too_high = Some(known.too_high.map_or(value, |bound| bound.min(value)))
+            }
+            (Verdict::TooLow, Some(value)) => {
+                known.too_low = Some(known.too_low.map_or(value, |bound| bound.max(value)))
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use utils::{Answer, Part};
+
+    use super::{HttpSubmitter, Refusal, Submissions, Submitter, Verdict};
+
+    #[test]
+    fn test_guards() {
+        let mut store = Submissions::default();
+        let answer = |value: i128| Answer::Integer(value);
+
+        store.record(1, Part::One, &answer(100), &Verdict::TooHigh);
+        store.record(1, Part::One, &answer(200), &Verdict::TooHigh);
+        store.record(1, Part::One, &answer(10), &Verdict::TooLow);
+        store.record(1, Part::One, &answer(50), &Verdict::Wrong);
+
+        assert_eq!(
+            store.check(1, Part::One, &answer(150)),
+            Err(Refusal::TooHigh(100))
+        );
+        assert_eq!(
+            store.check(1, Part::One, &answer(5)),
+            Err(Refusal::TooLow(10))
+        );
+        assert_eq!(
+            store.check(1, Part::One, &answer(50)),
+            Err(Refusal::KnownWrong)
+        );
+        assert_eq!(store.check(1, Part::One, &answer(60)), Ok(()));
+        assert_eq!(store.check(1, Part::Two, &answer(150)), Ok(()));
+
+        store.record(1, Part::One, &answer(60), &Verdict::Correct);
+        assert_eq!(
+            store.check(1, Part::One, &answer(60)),
+            Err(Refusal::AlreadyAccepted)
+        );
+        assert_eq!(
+            store.check(1, Part::One, &answer(61)),
+            Err(Refusal::DiffersFromAccepted("60".into()))
+        );
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too low. Please wait one minute."
+            )),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You gave an answer too recently. You have 42s left to wait."
+            )),
+            Ok(Verdict::TooRecent(Some("42s".into())))
+        );
+        assert!(Verdict::from_response("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_http_submitter() {
+        // A fake server that checks the form it was sent and responds like the real site would.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                request.push(line.trim_end().to_owned());
+            }
+
+            let length = request
+                .iter()
+                .find_map(|h| h.strip_prefix("Content-Length: "))
+                .unwrap()
+                .parse()
+                .unwrap();
+            let mut form = vec![0; length];
+            reader.read_exact(&mut form).unwrap();
+
+            let body = match (
+                request[0].as_str(),
+                request.iter().any(|h| h == "Cookie: session=secret"),
+                String::from_utf8(form).unwrap().as_str(),
+            ) {
+                ("POST /2022/day/3/answer HTTP/1.1", true, "level=2&answer=157") => {
+                    "<article><p>That's not the right answer; your answer is too high.</p></article>"
+                }
+                _ => "<article><p>Unexpected request</p></article>",
+            };
+
+            write!(
+                &stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let submitter = HttpSubmitter::new(base_url, "secret");
+        assert_eq!(submitter.submit(3, Part::Two, "157"), Ok(Verdict::TooHigh));
+
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use aoc::{answers::Answers, days, input};
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Runs every entry in `answers.toml` with the given speed, collecting all mismatches so a single
+/// failure doesn't hide the others.
+fn check_answers(slow: bool) {
+    let root = workspace_root();
+    let answers = Answers::load(&root.join("answers.toml")).unwrap();
+
+    let mut failures = vec![];
+    let mut checked = 0;
+
+    for entry in answers.entries.iter().filter(|e| e.slow == slow) {
+        let label = format!("day {:02} ({})", entry.day, entry.input);
+
+        let Some(puzzle) = days::find(entry.day) else {
+            failures.push(format!("{label}: day is not registered with the runner"));
+            continue;
+        };
+
+        let data = match input::read_file(&root.join(input::DEFAULT_DIR).join(&entry.input)) {
+            Ok(data) => data,
+            Err(err) => {
+                failures.push(format!("{label}: {err}"));
+                continue;
+            }
+        };
+
+        if let Err(err) = entry.params().check(puzzle.params()) {
+            failures.push(format!("{label}: day {err}"));
+            continue;
+        }
+
+        let run = match puzzle.run_with(&data, &entry.parts(), &entry.params()) {
+            Ok(run) => run,
+            Err(err) => {
+                failures.push(format!("{label}: {err}"));
+                continue;
+            }
+        };
+
+        for run in run.parts {
+            let expected = entry.expected(run.part).unwrap();
+            checked += 1;
+
+            match &run.answer {
+                Ok(answer) if expected.matches(answer) => {}
+                Ok(answer) => failures.push(format!(
+                    "{label} part {}: expected\n{expected}\ngot\n{answer}",
+                    run.part
+                )),
+                Err(err) => failures.push(format!("{label} part {}: {err}", run.part)),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {checked} answers were wrong:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_answers() {
+    check_answers(false);
+}
+
+#[test]
+#[ignore = "slow, run with --ignored"]
+fn test_slow_answers() {
+    check_answers(true);
+}
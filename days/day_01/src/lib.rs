@@ -0,0 +1,710 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    error::Error,
+    fmt::{self, Debug, Display},
+    io::{self, BufRead},
+    marker::PhantomData,
+    ops::Div,
+    str::FromStr,
+};
+
+use utils::{
+    geom::Number, lines, Answer, OverflowError, Params, ParseError, Puzzle, Solution, SolveError,
+};
+
+pub mod report;
+
+/// Day 1, with calories counted in `u32` as the puzzle needs.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Day01In::<u32>::parse(input)
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, SolveError> {
+        Day01In::<u32>::parse_with(input, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Day01In::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Day01In::part2(input)
+    }
+
+    fn report(input: &Self::Input) -> Option<String> {
+        <Day01In<u32> as Solution>::report(input)
+    }
+}
+
+/// Day 1, with calories counted in `T`, for inventories whose totals don't fit in `u32`.
+pub struct Day01In<T>(PhantomData<fn() -> T>);
+
+impl<T: Calories> Solution for Day01In<T> {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf<T>>;
+
+    /// Prefer `parse_with`, which keeps an overflow apart from input that's malformed.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(inventory(input)?)
+    }
+
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, SolveError> {
+        Ok(inventory(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        part1(input).map(Calories::answer)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(input).map(Calories::answer)
+    }
+
+    fn report(input: &Self::Input) -> Option<String> {
+        report::Report::new(input).map(|report| report.to_string())
+    }
+}
+
+pub fn part1<T: Calories>(elves: &[Elf<T>]) -> Result<T, SolveError> {
+    top_elves(elves, 1)
+        .top
+        .first()
+        .map(Elf::calories)
+        .ok_or(SolveError::no_solution("there are no elves"))
+}
+
+pub fn part2<T: Calories>(elves: &[Elf<T>]) -> Result<T, SolveError> {
+    let ranking = top_elves(elves, 3);
+    if ranking.top.len() < 3 {
+        let message = format!("need at least 3 elves, found {}", ranking.elves);
+        return Err(SolveError::no_solution(message));
+    }
+
+    ranking
+        .top
+        .iter()
+        .try_fold(T::ZERO, |total, elf| total.checked_add(elf.calories()))
+        .ok_or_else(|| {
+            let elves = ranking
+                .top
+                .iter()
+                .map(|elf| (elf.index() + 1).to_string())
+                .collect::<Vec<_>>();
+
+            SolveError::Overflow(OverflowError {
+                what: format!("the calories of elves {} together", elves.join(", ")),
+                line: None,
+                width: T::NAME,
+                hint: Width::hint(T::NAME),
+            })
+        })
+}
+
+/// The unsigned integer types calories can be counted in.
+pub trait Calories:
+    Number + Div<Output = Self> + From<u32> + FromStr + Display + Debug + Send + Sync + 'static
+{
+    /// The name of the type, for error messages.
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// The value as a `u128`, which every calorie type fits in.
+    fn widen(self) -> u128;
+
+    /// The value as an answer, which is only given as text if it's too large for an integer
+    /// answer.
+    fn answer(self) -> Answer {
+        match i128::try_from(self.widen()) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(self.to_string()),
+        }
+    }
+}
+
+macro_rules! impl_calories {
+    ($($ty:ty),*) => {
+        $(
+            impl Calories for $ty {
+                const NAME: &'static str = stringify!($ty);
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, other)
+                }
+
+                fn widen(self) -> u128 {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_calories!(u32, u64, u128);
+
+/// Which type the runner counts calories in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Width {
+    #[default]
+    U32,
+    U64,
+    U128,
+}
+
+impl Width {
+    pub const ALL: [Width; 3] = [Width::U32, Width::U64, Width::U128];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Width::U32 => u32::NAME,
+            Width::U64 => u64::NAME,
+            Width::U128 => u128::NAME,
+        }
+    }
+
+    /// How to count in something wider than the type called `name`, unless nothing is wider.
+    pub fn hint(name: &str) -> Option<String> {
+        let wider = Width::ALL
+            .iter()
+            .skip_while(|width| width.name() != name)
+            .skip(1)
+            .map(|width| format!("--width {}", width.name()))
+            .collect::<Vec<_>>();
+
+        match wider.is_empty() {
+            true => None,
+            false => Some(format!("count with {}", wider.join(" or "))),
+        }
+    }
+
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        match arg {
+            "u32" => Ok(Width::U32),
+            "u64" => Ok(Width::U64),
+            "u128" => Ok(Width::U128),
+            other => Err(format!(
+                "Unknown width: {other} (expected u32, u64 or u128)"
+            )),
+        }
+    }
+
+    /// Day 1, counting calories in this type.
+    pub fn puzzle(self) -> &'static dyn Puzzle {
+        match self {
+            Width::U32 => &Day01,
+            Width::U64 => &Day01In::<u64>(PhantomData),
+            Width::U128 => &Day01In::<u128>(PhantomData),
+        }
+    }
+}
+
+/// Reads every elf's items, where each elf's items are separated from the next elf's by blank
+/// lines.
+pub fn inventory<T: Calories>(input: &str) -> Result<Vec<Elf<T>>, InventoryError> {
+    let mut reader = ElfReader::default();
+    let mut elves = vec![];
+
+    for (_, line) in lines(input) {
+        elves.extend(reader.add_line(line)?);
+    }
+    elves.extend(reader.finish());
+
+    Ok(elves)
+}
+
+/// Ranks the `k` elves carrying the most calories.
+pub fn top_elves<T: Calories>(elves: &[Elf<T>], k: usize) -> Ranking<T> {
+    // Only the ranks are kept while looking, so just the winners' items are copied.
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (position, elf) in elves.iter().enumerate() {
+        keep_best(&mut heap, k, (elf.rank(), position));
+    }
+
+    Ranking {
+        top: best_first(heap)
+            .map(|(_, position)| elves[position].clone())
+            .collect(),
+        elves: elves.len(),
+    }
+}
+
+/// Adds `item` to a min-heap of the `k` greatest items, dropping the least of them if it's full.
+fn keep_best<E: Ord>(heap: &mut BinaryHeap<Reverse<E>>, k: usize, item: E) {
+    match heap.peek() {
+        _ if heap.len() < k => heap.push(Reverse(item)),
+        Some(Reverse(lowest)) if item > *lowest => {
+            heap.pop();
+            heap.push(Reverse(item));
+        }
+        _ => {}
+    }
+}
+
+/// Empties a heap filled by `keep_best`, from the greatest item to the least.
+fn best_first<E: Ord>(heap: BinaryHeap<Reverse<E>>) -> impl Iterator<Item = E> {
+    // Sorting the reversed items from least to greatest puts the greatest first.
+    heap.into_sorted_vec().into_iter().map(|Reverse(item)| item)
+}
+
+/// The calories of each snack an elf carries, and where the elf comes in the inventory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf<T = u32> {
+    index: usize,
+    items: Vec<T>,
+    calories: T,
+}
+
+impl<T: Calories> Elf<T> {
+    /// An elf carrying `items`, or `None` if they add up to more than fits in `T`.
+    pub fn new(index: usize, items: Vec<T>) -> Option<Self> {
+        let mut elf = Elf::empty(index);
+        for item in items {
+            elf.add(item)?;
+        }
+
+        Some(elf)
+    }
+
+    fn empty(index: usize) -> Self {
+        Elf {
+            index,
+            items: vec![],
+            calories: T::ZERO,
+        }
+    }
+
+    /// Adds an item, unless the total would no longer fit in `T`.
+    fn add(&mut self, item: T) -> Option<()> {
+        self.calories = self.calories.checked_add(item)?;
+        self.items.push(item);
+
+        Some(())
+    }
+
+    /// 0-based position of the elf's items in the inventory.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// The calories of all the elf's items together.
+    pub fn calories(&self) -> T {
+        self.calories
+    }
+
+    /// More calories rank higher, and the earlier elf ranks higher between two that carry the
+    /// same amount.
+    fn rank(&self) -> (T, Reverse<usize>) {
+        (self.calories, Reverse(self.index))
+    }
+}
+
+impl<T: Calories> PartialOrd for Elf<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Calories> Ord for Elf<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+/// Puts together elves from an inventory read one line at a time.
+#[derive(Debug, Clone)]
+struct ElfReader<T> {
+    /// How many lines have been read, which is the 1-based number of the last one.
+    lines: usize,
+    elves: usize,
+    /// The elf whose items are being read, if any have been yet.
+    current: Option<Elf<T>>,
+}
+
+impl<T> Default for ElfReader<T> {
+    fn default() -> Self {
+        ElfReader {
+            lines: 0,
+            elves: 0,
+            current: None,
+        }
+    }
+}
+
+impl<T: Calories> ElfReader<T> {
+    /// Reads the next line, returning the elf it finishes if it's the blank line after one.
+    fn add_line(&mut self, line: &str) -> Result<Option<Elf<T>>, InventoryError> {
+        self.lines += 1;
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            return Ok(self.finish());
+        }
+
+        let overflow = || Overflow {
+            elf: self.elves,
+            line: self.lines,
+            text: line.to_owned(),
+            width: T::NAME,
+        };
+
+        // An unsigned integer made up of nothing but digits can only fail to parse by being too
+        // large.
+        let item = match line.parse::<T>() {
+            Ok(item) => item,
+            Err(_) if line.bytes().all(|b| b.is_ascii_digit()) => return Err(overflow().into()),
+            Err(_) => {
+                return Err(ParseError::new("a calorie count", line)
+                    .at_line(self.lines)
+                    .into())
+            }
+        };
+
+        let elves = self.elves;
+        let elf = self.current.get_or_insert_with(|| Elf::empty(elves));
+        elf.add(item).ok_or_else(overflow)?;
+
+        Ok(None)
+    }
+
+    /// Finishes the elf being read, if there is one.
+    fn finish(&mut self) -> Option<Elf<T>> {
+        let elf = self.current.take()?;
+        self.elves += 1;
+
+        Some(elf)
+    }
+}
+
+/// The elves carrying the most calories out of a whole inventory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking<T = u32> {
+    /// At most `k` elves, from the most calories to the least.
+    pub top: Vec<Elf<T>>,
+    /// How many elves there were altogether.
+    pub elves: usize,
+}
+
+/// Totals an inventory one line at a time, only remembering the `k` elves carrying the most
+/// calories so far. Each elf's items are separated from the next elf's by blank lines.
+#[derive(Debug, Clone)]
+pub struct TopElves<T = u32> {
+    k: usize,
+    /// A min-heap, so the elf that drops out when a better one comes along is on top.
+    heap: BinaryHeap<Reverse<Elf<T>>>,
+    elves: usize,
+    reader: ElfReader<T>,
+}
+
+impl<T: Calories> TopElves<T> {
+    pub fn new(k: usize) -> Self {
+        TopElves {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+            elves: 0,
+            reader: ElfReader::default(),
+        }
+    }
+
+    /// Reads the next line of the inventory, without its line ending.
+    pub fn add_line(&mut self, line: &str) -> Result<(), InventoryError> {
+        if let Some(elf) = self.reader.add_line(line)? {
+            self.push(elf);
+        }
+
+        Ok(())
+    }
+
+    /// Adds an elf that has already been read, which is counted whether or not it's kept.
+    pub fn push(&mut self, elf: Elf<T>) {
+        self.elves += 1;
+        keep_best(&mut self.heap, self.k, elf);
+    }
+
+    /// Finishes the last elf and ranks the ones that were kept.
+    pub fn finish(mut self) -> Ranking<T> {
+        if let Some(elf) = self.reader.finish() {
+            self.push(elf);
+        }
+
+        Ranking {
+            top: best_first(self.heap).collect(),
+            elves: self.elves,
+        }
+    }
+}
+
+/// An elf whose calories add up to more than the type counting them can hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// 0-based position of the elf in the inventory.
+    pub elf: usize,
+    /// 1-based line of the item that didn't fit.
+    pub line: usize,
+    pub text: String,
+    /// The name of the type the calories were counted in.
+    pub width: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: elf {} carries more calories than fit in {}",
+            self.line,
+            self.elf + 1,
+            self.width
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// Why an inventory couldn't be read.
+#[derive(Debug)]
+pub enum InventoryError {
+    Io(io::Error),
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+impl Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InventoryError::Io(err) => write!(f, "Could not read the inventory: {err}"),
+            InventoryError::Parse(err) => write!(f, "Invalid inventory: {err}"),
+            InventoryError::Overflow(err) => write!(f, "Calorie overflow: {err}"),
+        }
+    }
+}
+
+impl Error for InventoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InventoryError::Io(err) => Some(err),
+            InventoryError::Parse(err) => Some(err),
+            InventoryError::Overflow(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for InventoryError {
+    fn from(err: io::Error) -> Self {
+        InventoryError::Io(err)
+    }
+}
+
+impl From<ParseError> for InventoryError {
+    fn from(err: ParseError) -> Self {
+        InventoryError::Parse(err)
+    }
+}
+
+impl From<Overflow> for InventoryError {
+    fn from(err: Overflow) -> Self {
+        InventoryError::Overflow(err)
+    }
+}
+
+impl From<InventoryError> for ParseError {
+    /// Only for `Solution::parse`, which has no other way to report an overflow.
+    fn from(err: InventoryError) -> Self {
+        match err {
+            InventoryError::Io(err) => ParseError::new("a readable inventory", err.to_string()),
+            InventoryError::Parse(err) => err,
+            InventoryError::Overflow(err) => ParseError::new(
+                format!(
+                    "the calories of elf {} to fit in {}",
+                    err.elf + 1,
+                    err.width
+                ),
+                err.text,
+            )
+            .at_line(err.line),
+        }
+    }
+}
+
+impl From<Overflow> for OverflowError {
+    fn from(err: Overflow) -> Self {
+        OverflowError {
+            what: format!("the calories of elf {}", err.elf + 1),
+            line: Some(err.line),
+            width: err.width,
+            hint: Width::hint(err.width),
+        }
+    }
+}
+
+impl From<InventoryError> for SolveError {
+    fn from(err: InventoryError) -> Self {
+        match err {
+            InventoryError::Overflow(err) => SolveError::Overflow(err.into()),
+            err => SolveError::Parse(err.into()),
+        }
+    }
+}
+
+/// Ranks the `k` elves carrying the most calories in an inventory read from `reader`, holding
+/// only one line of it in memory at a time.
+pub fn read_top_elves<T: Calories>(
+    mut reader: impl BufRead,
+    k: usize,
+) -> Result<Ranking<T>, InventoryError> {
+    let mut top = TopElves::new(k);
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(top.finish());
+        }
+
+        top.add_line(&line)?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use utils::{Answer, Params, Solution, SolveError};
+
+    use crate::{
+        inventory, part2, read_top_elves, top_elves, Day01, Day01In, Elf, InventoryError, Overflow,
+    };
+
+    const INVENTORY: &str =
+        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n\n10000\n";
+
+    fn elf(index: usize, items: &[u32]) -> Elf {
+        Elf::new(index, items.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_read_top_elves() {
+        let ranking = read_top_elves(INVENTORY.as_bytes(), 3).unwrap();
+        assert_eq!(ranking.elves, 5);
+        assert_eq!(
+            ranking.top,
+            [
+                elf(3, &[7000, 8000, 9000]),
+                elf(2, &[5000, 6000]),
+                elf(4, &[10000])
+            ]
+        );
+
+        let ranking = read_top_elves(INVENTORY.as_bytes(), 10).unwrap();
+        assert_eq!(ranking.top.len(), 5);
+        assert_eq!(ranking.top[4], elf(1, &[4000]));
+
+        assert!(read_top_elves::<u32>(INVENTORY.as_bytes(), 0)
+            .unwrap()
+            .top
+            .is_empty());
+        assert_eq!(read_top_elves::<u32>("".as_bytes(), 3).unwrap().elves, 0);
+    }
+
+    #[test]
+    fn test_ties() {
+        let input = "5\n\n7\n\n5\n\n5\r\n";
+        let ranking = read_top_elves(input.as_bytes(), 2).unwrap();
+
+        assert_eq!(ranking.top, [elf(1, &[7]), elf(0, &[5])]);
+        assert_eq!(top_elves(&inventory(input).unwrap(), 2), ranking);
+    }
+
+    #[test]
+    fn test_read_error() {
+        let err = read_top_elves::<u32>("1000\n\n20x0\n".as_bytes(), 3).unwrap_err();
+
+        let InventoryError::Parse(err) = err else {
+            panic!("expected a parse error, got {err}");
+        };
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn test_typed_overflow() {
+        // The runner parses with `parse_with`, which keeps an overflow apart from a parse error.
+        let err = Day01::parse_with("1\n4294967295\n", &Params::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "overflow: line 2: the calories of elf 1 don't fit in u32 \
+             (count with --width u64 or --width u128)"
+        );
+
+        let elves = inventory::<u32>(&"4000000000\n\n".repeat(3)).unwrap();
+        let Err(SolveError::Overflow(err)) = part2(&elves) else {
+            panic!("expected an overflow");
+        };
+        assert_eq!(err.what, "the calories of elves 1, 2, 3 together");
+        assert_eq!(err.width, "u32");
+        assert_eq!(
+            err.hint.as_deref(),
+            Some("count with --width u64 or --width u128")
+        );
+        assert_eq!(
+            part2(&inventory::<u64>(&"4000000000\n\n".repeat(3)).unwrap()),
+            Ok(12000000000)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "1\n\n4000000000\n300000000\n\n5000000000\n";
+
+        let err = inventory::<u32>(input).unwrap_err();
+        let InventoryError::Overflow(overflow) = err else {
+            panic!("expected an overflow, got {err}");
+        };
+        assert_eq!(
+            overflow,
+            Overflow {
+                elf: 1,
+                line: 4,
+                text: "300000000".into(),
+                width: "u32"
+            }
+        );
+        assert_eq!(
+            overflow.to_string(),
+            "line 4: elf 2 carries more calories than fit in u32"
+        );
+
+        // A single item that's too large is an overflow as well.
+        let err = inventory::<u32>("5000000000\n").unwrap_err();
+        assert!(matches!(
+            err,
+            InventoryError::Overflow(Overflow {
+                elf: 0,
+                line: 1,
+                ..
+            })
+        ));
+
+        let elves = inventory::<u64>(input).unwrap();
+        assert_eq!(part2(&elves).unwrap(), 9300000001);
+
+        let elves =
+            inventory::<u128>(&"340282366920938463463374607431768211455\n\n".repeat(3)).unwrap();
+        let Err(SolveError::Overflow(err)) = part2(&elves) else {
+            panic!("expected an overflow");
+        };
+        assert_eq!(err.hint, None);
+        assert_eq!(
+            Day01In::part1(&elves).unwrap(),
+            Answer::Text(u128::MAX.to_string())
+        );
+    }
+}
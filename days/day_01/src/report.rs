@@ -0,0 +1,225 @@
+use std::fmt::{self, Display};
+
+use crate::{Calories, Elf};
+
+/// The percentiles given in a report, besides the median.
+pub const PERCENTILES: [u8; 4] = [10, 25, 75, 90];
+
+/// The most buckets the calorie totals are spread over in the histogram.
+pub const BUCKETS: u32 = 10;
+
+/// How many times the spread of the middle half of the elves a total has to be beyond that half
+/// to count as an outlier, as in Tukey's fences.
+const FENCE: f64 = 1.5;
+
+/// How many calories the elves carry between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report<T = u32> {
+    pub elves: usize,
+    pub items: usize,
+    /// The calories of every elf together, which only stops growing at `u128::MAX`.
+    pub total: u128,
+    pub mean: f64,
+    pub median: f64,
+    /// The calories under which the given percentage of elves fall, by nearest rank.
+    pub percentiles: Vec<(u8, T)>,
+    pub histogram: Vec<Bucket<T>>,
+    /// Elves whose total is far from the middle half of the elves, in inventory order.
+    pub outliers: Vec<Elf<T>>,
+}
+
+/// A range of calorie totals, and how many elves carry a total in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket<T = u32> {
+    pub from: T,
+    pub to: T,
+    pub elves: usize,
+}
+
+impl<T: Calories> Report<T> {
+    /// Describes the elves, or `None` if there are none.
+    pub fn new(elves: &[Elf<T>]) -> Option<Self> {
+        if elves.is_empty() {
+            return None;
+        }
+
+        let mut sorted = elves.iter().map(Elf::calories).collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        let total = sorted.iter().fold(0u128, |total, calories| {
+            total.saturating_add(calories.widen())
+        });
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (to_f64(sorted[middle - 1]) + to_f64(sorted[middle])) / 2.0,
+            _ => to_f64(sorted[middle]),
+        };
+
+        let (q1, q3) = (
+            to_f64(percentile(&sorted, 25)),
+            to_f64(percentile(&sorted, 75)),
+        );
+        let (low, high) = (q1 - FENCE * (q3 - q1), q3 + FENCE * (q3 - q1));
+
+        Some(Report {
+            elves: elves.len(),
+            items: elves.iter().map(|elf| elf.items().len()).sum(),
+            total,
+            mean: total as f64 / elves.len() as f64,
+            median,
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&sorted, p)))
+                .collect(),
+            histogram: histogram(&sorted),
+            outliers: elves
+                .iter()
+                .filter(|elf| {
+                    let calories = to_f64(elf.calories());
+                    calories < low || calories > high
+                })
+                .cloned()
+                .collect(),
+        })
+    }
+}
+
+/// The `p`th percentile of `sorted`, by the nearest-rank method.
+fn percentile<T: Calories>(sorted: &[T], p: u8) -> T {
+    let rank = (usize::from(p) * sorted.len()).div_ceil(100);
+
+    sorted[rank.saturating_sub(1)]
+}
+
+/// Spreads `sorted` over at most `BUCKETS` buckets of the same width, from the smallest value to
+/// the largest.
+fn histogram<T: Calories>(sorted: &[T]) -> Vec<Bucket<T>> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = (max - min) / T::from(BUCKETS) + T::ONE;
+    // There are at most `BUCKETS` of them, so the index of each fits in anything.
+    let bucket = |calories: T| ((calories - min) / width).widen() as u32;
+
+    let mut buckets = (0..=bucket(max))
+        .map(|i| {
+            let from = min + T::from(i) * width;
+            Bucket {
+                from,
+                to: from
+                    .checked_add(width - T::ONE)
+                    .map_or(max, |to| to.min(max)),
+                elves: 0,
+            }
+        })
+        .collect::<Vec<_>>();
+    for &calories in sorted {
+        buckets[bucket(calories) as usize].elves += 1;
+    }
+
+    buckets
+}
+
+/// Close enough for statistics, even for totals too large to be exact.
+fn to_f64<T: Calories>(value: T) -> f64 {
+    value.widen() as f64
+}
+
+impl<T: Calories> Display for Report<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} elves carry {} items with {} calories in all",
+            self.elves, self.items, self.total
+        )?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(p, calories)| format!("{p}th {calories}"))
+            .collect::<Vec<_>>();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+
+        writeln!(f, "Histogram:")?;
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let digits = self.histogram.last().map_or(1, |b| b.to.to_string().len());
+        for bucket in &self.histogram {
+            // Bars are scaled so the longest is 40 characters, and any non-empty bucket shows.
+            let bar = (bucket.elves * 40).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "  {:>digits$}..={:<digits$} {} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.elves
+            )?;
+        }
+
+        let outliers = self
+            .outliers
+            .iter()
+            .map(|elf| format!("elf {} with {}", elf.index() + 1, elf.calories()))
+            .collect::<Vec<_>>();
+        match outliers.is_empty() {
+            true => write!(f, "Outliers: none"),
+            false => write!(f, "Outliers: {}", outliers.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bucket, Report};
+    use crate::{inventory, Elf};
+
+    #[test]
+    fn test_report() {
+        let elves =
+            inventory::<u32>("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000")
+                .unwrap();
+        let report = Report::new(&elves).unwrap();
+
+        assert_eq!((report.elves, report.items, report.total), (5, 10, 55000));
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(
+            report.percentiles,
+            [(10, 4000), (25, 6000), (75, 11000), (90, 24000)]
+        );
+        assert_eq!(report.histogram.len(), 10);
+        assert_eq!(
+            report.histogram[0],
+            Bucket {
+                from: 4000,
+                to: 6000,
+                elves: 2
+            }
+        );
+        assert_eq!(report.histogram[9].to, 24000);
+        assert_eq!(report.histogram.iter().map(|b| b.elves).sum::<usize>(), 5);
+        assert_eq!(report.outliers, [elves[3].clone()]);
+        assert!(report.to_string().ends_with("Outliers: elf 4 with 24000"));
+    }
+
+    #[test]
+    fn test_small_reports() {
+        assert_eq!(Report::<u32>::new(&[]), None);
+
+        let elves = [
+            Elf::new(0, vec![7u32]).unwrap(),
+            Elf::new(1, vec![7]).unwrap(),
+        ];
+        let report = Report::new(&elves).unwrap();
+        assert_eq!(report.median, 7.0);
+        assert_eq!(
+            report.histogram,
+            [Bucket {
+                from: 7,
+                to: 7,
+                elves: 2
+            }]
+        );
+        assert!(report.outliers.is_empty());
+    }
+}
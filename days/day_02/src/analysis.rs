@@ -0,0 +1,294 @@
+use std::fmt::{self, Display};
+
+use utils::SolveError;
+
+use crate::{
+    rules::{Outcome, Rules, Shape},
+    Decoder, Round,
+};
+
+/// One way of reading the second column of the strategy guide: how it's decoded, and which shape
+/// or outcome `X`, `Y` and `Z` each stand for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reading {
+    pub decoder: Decoder,
+    /// What each letter stands for, as a position in the rules' shapes or in `Outcome::ALL`.
+    pub mapping: [usize; 3],
+}
+
+impl Reading {
+    /// How part 1 reads the guide.
+    pub const PART1: Reading = Reading {
+        decoder: Decoder::Shape,
+        mapping: [0, 1, 2],
+    };
+
+    /// How part 2 reads the guide.
+    pub const PART2: Reading = Reading {
+        decoder: Decoder::Outcome,
+        mapping: [0, 1, 2],
+    };
+
+    /// Every way of having `X`, `Y` and `Z` stand for different shapes, then for different
+    /// outcomes.
+    pub fn all(rules: &Rules) -> Vec<Reading> {
+        let shapes = mappings(rules.len()).map(|mapping| Reading {
+            decoder: Decoder::Shape,
+            mapping,
+        });
+        let outcomes = mappings(Outcome::ALL.len()).map(|mapping| Reading {
+            decoder: Decoder::Outcome,
+            mapping,
+        });
+
+        shapes.chain(outcomes).collect()
+    }
+
+    /// The round as the plain decoder sees it once the letters have been swapped around.
+    fn remap(&self, round: Round) -> Round {
+        Round {
+            response: self.mapping[round.response],
+            ..round
+        }
+    }
+
+    /// The shapes the opponent and you play in a round.
+    pub fn decode(&self, rules: &Rules, round: Round) -> Result<(Shape, Shape), SolveError> {
+        self.decoder.decode(rules, self.remap(round))
+    }
+
+    /// What each letter stands for, such as "X=Rock Y=Paper Z=Scissors".
+    pub fn describe(&self, rules: &Rules) -> String {
+        let names = self.mapping.iter().map(|&target| match self.decoder {
+            Decoder::Shape => rules.name(Shape(target)).to_owned(),
+            Decoder::Outcome => format!("{:?}", Outcome::ALL[target]),
+        });
+
+        ["X", "Y", "Z"]
+            .iter()
+            .zip(names)
+            .map(|(letter, name)| format!("{letter}={name}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Every way of picking a different one of `count` targets for each of `X`, `Y` and `Z`.
+fn mappings(count: usize) -> impl Iterator<Item = [usize; 3]> {
+    (0..count)
+        .flat_map(move |x| (0..count).flat_map(move |y| (0..count).map(move |z| [x, y, z])))
+        .filter(|[x, y, z]| x != y && y != z && x != z)
+}
+
+/// The points for each round of the strategy guide under a reading.
+pub fn breakdown(
+    rules: &Rules,
+    guide: &[Round],
+    reading: &Reading,
+) -> Result<Vec<u32>, SolveError> {
+    guide
+        .iter()
+        .map(|round| {
+            let (opponent, you) = reading.decode(rules, *round)?;
+            Ok(rules.score(you, opponent))
+        })
+        .collect()
+}
+
+/// How the strategy guide scores under every reading of its second column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// Every reading that could be played, with its description and total score, in the order
+    /// of `Reading::all`.
+    pub scores: Vec<(Reading, String, u32)>,
+    /// The guide aggregated by distinct line rather than listed round by round, which would run
+    /// to thousands of rows for a real input. Sorted by line.
+    pub rows: Vec<Row>,
+}
+
+/// The points for a single round of one distinct line of the guide, under the puzzle's readings
+/// and the best and worst ones. Multiplying by `count` gives what the line adds to the total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub line: String,
+    /// How many times the line appears in the guide.
+    pub count: usize,
+    pub part1: u32,
+    pub part2: u32,
+    pub best: u32,
+    pub worst: u32,
+}
+
+impl Analysis {
+    /// Scores `guide` under every reading. Readings that leave some round without a shape to
+    /// play are left out.
+    pub fn new(rules: &Rules, guide: &[Round]) -> Self {
+        let scores = Reading::all(rules)
+            .into_iter()
+            .filter_map(|reading| {
+                let points = breakdown(rules, guide, &reading).ok()?;
+                Some((reading, reading.describe(rules), points.iter().sum()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut lines = guide.to_vec();
+        lines.sort_by_key(|round| (round.opponent, round.response));
+        lines.dedup();
+
+        let mut analysis = Analysis {
+            scores,
+            rows: vec![],
+        };
+        let (Some(&(best, ..)), Some(&(worst, ..))) = (analysis.best(), analysis.worst()) else {
+            return analysis;
+        };
+
+        let points = |reading: &Reading, round: Round| {
+            let (opponent, you) = reading.decode(rules, round).ok()?;
+            Some(rules.score(you, opponent))
+        };
+
+        analysis.rows = lines
+            .into_iter()
+            .filter_map(|round| {
+                Some(Row {
+                    line: line(round),
+                    count: guide.iter().filter(|r| **r == round).count(),
+                    part1: points(&Reading::PART1, round)?,
+                    part2: points(&Reading::PART2, round)?,
+                    best: points(&best, round)?,
+                    worst: points(&worst, round)?,
+                })
+            })
+            .collect();
+
+        analysis
+    }
+
+    /// The reading with the highest score, the first of them if there's a tie.
+    pub fn best(&self) -> Option<&(Reading, String, u32)> {
+        self.scores.iter().rev().max_by_key(|(_, _, score)| *score)
+    }
+
+    /// The reading with the lowest score, the first of them if there's a tie.
+    pub fn worst(&self) -> Option<&(Reading, String, u32)> {
+        self.scores.iter().min_by_key(|(_, _, score)| *score)
+    }
+
+    pub fn score(&self, reading: &Reading) -> Option<u32> {
+        self.scores
+            .iter()
+            .find(|(r, _, _)| r == reading)
+            .map(|(_, _, score)| *score)
+    }
+}
+
+/// The line of the guide a round was read from, such as "A Y".
+fn line(round: Round) -> String {
+    let letter = |first: u8, code: usize| char::from(first + code as u8);
+
+    format!(
+        "{} {}",
+        letter(b'A', round.opponent),
+        letter(b'X', round.response)
+    )
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let best = self.best().map(|(reading, _, _)| reading);
+        let worst = self.worst().map(|(reading, _, _)| reading);
+
+        writeln!(f, "Scores by reading:")?;
+        for (reading, description, score) in &self.scores {
+            let notes = [
+                (Some(reading) == best, "best"),
+                (Some(reading) == worst, "worst"),
+                (*reading == Reading::PART1, "part 1"),
+                (*reading == Reading::PART2, "part 2"),
+            ]
+            .iter()
+            .filter(|(applies, _)| *applies)
+            .map(|(_, note)| *note)
+            .collect::<Vec<_>>();
+
+            write!(f, "  {:<8} {description:<28} {score:>6}", decoder(reading))?;
+            match notes.is_empty() {
+                true => writeln!(f)?,
+                false => writeln!(f, "  ({})", notes.join(", "))?,
+            }
+        }
+
+        writeln!(f, "Points for one round of each distinct line:")?;
+        writeln!(f, "  line  count  part 1  part 2    best   worst")?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "  {}  {:>5}  {:>6}  {:>6}  {:>6}  {:>6}",
+                row.line, row.count, row.part1, row.part2, row.best, row.worst
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn decoder(reading: &Reading) -> &'static str {
+    match reading.decoder {
+        Decoder::Shape => "shapes",
+        Decoder::Outcome => "outcomes",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{Analysis, Reading};
+    use crate::{rules::Rules, total_score, Decoder, Round};
+
+    #[test]
+    fn test_analysis() {
+        let rules = Rules::rock_paper_scissors();
+        let guide = ["A Y", "B X", "C Z"]
+            .iter()
+            .map(|line| Round::from_str(line).unwrap())
+            .collect::<Vec<_>>();
+
+        let analysis = Analysis::new(&rules, &guide);
+        assert_eq!(analysis.scores.len(), 12);
+        assert_eq!(analysis.score(&Reading::PART1), Some(15));
+        assert_eq!(analysis.score(&Reading::PART2), Some(12));
+        for (reading, _, score) in &analysis.scores {
+            let remapped = guide
+                .iter()
+                .map(|round| reading.remap(*round))
+                .collect::<Vec<_>>();
+            assert_eq!(total_score(&rules, &remapped, reading.decoder), Ok(*score));
+        }
+
+        // Winning every round scores 6 a round plus the shape that wins, which is Paper, Scissors
+        // and Rock here: (6 + 2) + (6 + 3) + (6 + 1). Losing every round scores 1 + 2 + 3.
+        let (best, description, score) = analysis.best().unwrap();
+        assert_eq!(*score, 24);
+        assert_eq!(best.decoder, Decoder::Shape);
+        assert_eq!(description, "X=Scissors Y=Paper Z=Rock");
+        assert_eq!(analysis.worst().unwrap().2, 6);
+
+        assert_eq!(analysis.rows.len(), 3);
+        assert_eq!(analysis.rows[0].line, "A Y");
+        assert_eq!((analysis.rows[0].part1, analysis.rows[0].part2), (8, 4));
+        assert_eq!(analysis.rows.iter().map(|row| row.best).sum::<u32>(), 24);
+
+        // Repeated lines share a row, which still adds up to the whole guide's score.
+        let repeated = [guide[0], guide[0], guide[1]];
+        let analysis = Analysis::new(&rules, &repeated);
+        assert_eq!(analysis.rows.len(), 2);
+        assert_eq!(
+            (analysis.rows[0].line.as_str(), analysis.rows[0].count),
+            ("A Y", 2)
+        );
+        let total = analysis.rows.iter().map(|row| row.count as u32 * row.part1);
+        assert_eq!(Some(total.sum()), analysis.score(&Reading::PART1));
+    }
+}
@@ -0,0 +1,158 @@
+use std::str::FromStr;
+
+use rules::{Outcome, Rules, Shape};
+use utils::{parse_lines, Answer, ParseError, Solution, SolveError};
+
+pub mod analysis;
+pub mod rules;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(total_score(&Rules::rock_paper_scissors(), input, Decoder::Shape)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(total_score(&Rules::rock_paper_scissors(), input, Decoder::Outcome)?.into())
+    }
+
+    fn report(input: &Self::Input) -> Option<String> {
+        Some(analysis::Analysis::new(&Rules::rock_paper_scissors(), input).to_string())
+    }
+}
+
+/// The points for playing every round of the strategy guide, reading it with `decoder`.
+pub fn total_score(rules: &Rules, guide: &[Round], decoder: Decoder) -> Result<u32, SolveError> {
+    guide
+        .iter()
+        .map(|round| {
+            let (opponent, you) = decoder.decode(rules, *round)?;
+            Ok(rules.score(you, opponent))
+        })
+        .sum()
+}
+
+/// A line of the strategy guide, with each column turned into its position in the alphabet the
+/// column is written in, so `A` and `X` are both 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub response: usize,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent, response) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new("\"<A|B|C> <X|Y|Z>\"", s))?;
+
+        let code = |column: &str, letters: [&str; 3], expected: &str| {
+            letters
+                .iter()
+                .position(|&letter| letter == column)
+                .ok_or_else(|| ParseError::new(expected, column).within(s, column))
+        };
+
+        Ok(Round {
+            opponent: code(opponent, ["A", "B", "C"], "a shape (A, B or C)")?,
+            response: code(response, ["X", "Y", "Z"], "X, Y or Z")?,
+        })
+    }
+}
+
+/// How the second column of the strategy guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoder {
+    /// It's the shape to play, as part 1 guesses.
+    Shape,
+    /// It's how the round needs to end, with `X` for a loss, `Y` for a draw and `Z` for a win.
+    Outcome,
+}
+
+impl Decoder {
+    /// The shapes the opponent and you play in a round.
+    pub fn decode(self, rules: &Rules, round: Round) -> Result<(Shape, Shape), SolveError> {
+        let shape = |code: usize| match code < rules.len() {
+            true => Ok(Shape(code)),
+            false => Err(SolveError::invariant(format!(
+                "the game only has {} shapes",
+                rules.len()
+            ))),
+        };
+
+        let opponent = shape(round.opponent)?;
+        let you = match self {
+            Decoder::Shape => shape(round.response)?,
+            Decoder::Outcome => {
+                let outcome = *Outcome::ALL.get(round.response).ok_or_else(|| {
+                    SolveError::invariant("there are only three ways a round can end")
+                })?;
+
+                rules.respond(opponent, outcome).ok_or_else(|| {
+                    SolveError::no_solution(format!(
+                        "nothing gives a {outcome:?} against {}",
+                        rules.name(opponent)
+                    ))
+                })?
+            }
+        };
+
+        Ok((opponent, you))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{rules::Rules, total_score, Decoder, Round};
+
+    #[test]
+    fn test_points() {
+        let rules = Rules::rock_paper_scissors();
+        let points = |round: &str, decoder: Decoder| {
+            total_score(&rules, &[Round::from_str(round).unwrap()], decoder).unwrap()
+        };
+
+        assert_eq!(points("A Y", Decoder::Shape), 8);
+        assert_eq!(points("B X", Decoder::Shape), 1);
+        assert_eq!(points("C Z", Decoder::Shape), 6);
+
+        assert_eq!(points("A Y", Decoder::Outcome), 4);
+        assert_eq!(points("B X", Decoder::Outcome), 1);
+        assert_eq!(points("C Z", Decoder::Outcome), 7);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Round::from_str("A Q").err().unwrap();
+        assert_eq!(err.column, Some(3));
+        assert_eq!(err.text, "Q");
+    }
+
+    #[test]
+    fn test_variant() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let round = |opponent, response| Round { opponent, response };
+        let guide = [round(1, 2), round(4, 0)];
+
+        // Paper beats Spock and Rock beats Scissors, or Paper is the first shape to beat Spock
+        // and the first to lose to Scissors.
+        assert_eq!(total_score(&rules, &guide, Decoder::Shape), Ok(9 + 7));
+        assert_eq!(total_score(&rules, &guide, Decoder::Outcome), Ok(9 + 3));
+
+        assert!(total_score(&rules, &[round(0, 3)], Decoder::Outcome).is_err());
+        assert!(total_score(&rules, &[round(0, 4)], Decoder::Shape).is_ok());
+    }
+}
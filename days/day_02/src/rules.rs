@@ -0,0 +1,218 @@
+/// One of the shapes in a game, numbered by its position in the `Rules`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+/// How many points a player gets for a round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    /// Points for the shape played, by shape.
+    pub shapes: Vec<u32>,
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl Scoring {
+    /// The puzzle's scoring: 1 point for the first shape, 2 for the second and so on, plus 0, 3
+    /// or 6 for a loss, draw or win.
+    pub fn standard(shapes: usize) -> Self {
+        Scoring {
+            shapes: (1..=shapes as u32).collect(),
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+
+    pub fn outcome(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+/// A game in the style of Rock Paper Scissors: the shapes that can be played, which of them
+/// beats which, and how rounds are scored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// Whether the first shape beats the second, by shape.
+    beats: Vec<Vec<bool>>,
+    scoring: Scoring,
+}
+
+impl Rules {
+    /// A game where each `(winner, loser)` pair in `beats` says which of two shapes wins. Every
+    /// two different shapes must be decided by exactly one pair, so that no round is left open.
+    pub fn new(names: &[&str], beats: &[(usize, usize)], scoring: Scoring) -> Result<Self, String> {
+        let count = names.len();
+        if scoring.shapes.len() != count {
+            return Err(format!(
+                "{count} shapes but points for {}",
+                scoring.shapes.len()
+            ));
+        }
+
+        let mut table = vec![vec![false; count]; count];
+        for &(winner, loser) in beats {
+            if winner >= count || loser >= count || winner == loser {
+                return Err(format!("{winner} can't beat {loser} with {count} shapes"));
+            }
+
+            table[winner][loser] = true;
+        }
+
+        for a in 0..count {
+            for b in a + 1..count {
+                if table[a][b] == table[b][a] {
+                    return Err(format!(
+                        "{} and {} need exactly one winner",
+                        names[a], names[b]
+                    ));
+                }
+            }
+        }
+
+        Ok(Rules {
+            names: names.iter().map(|&name| name.to_owned()).collect(),
+            beats: table,
+            scoring,
+        })
+    }
+
+    /// A game of an odd number of shapes, where each shape beats the half of the others just
+    /// before it, wrapping around from the first shape to the last.
+    pub fn cyclic(names: &[&str], scoring: Scoring) -> Result<Self, String> {
+        let count = names.len();
+        if count.is_multiple_of(2) {
+            return Err(format!(
+                "{count} shapes can't all beat as many shapes as they lose to"
+            ));
+        }
+
+        let beats = (0..count)
+            .flat_map(|winner| (1..=count / 2).map(move |d| (winner, (winner + count - d) % count)))
+            .collect::<Vec<_>>();
+
+        Rules::new(names, &beats, scoring)
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Rules::cyclic(&["Rock", "Paper", "Scissors"], Scoring::standard(3)).unwrap()
+    }
+
+    /// The version with five shapes, ordered so that each beats the two before it.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let names = ["Rock", "Spock", "Paper", "Lizard", "Scissors"];
+
+        Rules::cyclic(&names, Scoring::standard(5)).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.len()).map(Shape)
+    }
+
+    /// The shape with the given name, if there is one.
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    /// How a round ends for whoever plays `you`.
+    pub fn outcome(&self, you: Shape, opponent: Shape) -> Outcome {
+        if you == opponent {
+            Outcome::Draw
+        } else if self.beats[you.0][opponent.0] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// The first shape that ends a round against `opponent` with `outcome`, or `None` if there
+    /// isn't one.
+    pub fn respond(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .find(|&you| self.outcome(you, opponent) == outcome)
+    }
+
+    /// The points for whoever plays `you`.
+    pub fn score(&self, you: Shape, opponent: Shape) -> u32 {
+        self.scoring.shapes[you.0] + self.scoring.outcome(self.outcome(you, opponent))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Outcome, Rules, Scoring};
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let shape = |name| rules.shape(name).unwrap();
+
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert_eq!(rules.outcome(shape(winner), shape(loser)), Outcome::Win);
+            assert_eq!(rules.outcome(shape(loser), shape(winner)), Outcome::Loss);
+        }
+
+        assert_eq!(rules.score(shape("Lizard"), shape("Spock")), 10);
+        assert_eq!(
+            rules.respond(shape("Rock"), Outcome::Win),
+            Some(shape("Spock"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(Rules::cyclic(&["A", "B"], Scoring::standard(2)).is_err());
+        assert!(Rules::cyclic(&["A", "B", "C"], Scoring::standard(2)).is_err());
+
+        let err = Rules::new(&["A", "B", "C"], &[(0, 1), (1, 2)], Scoring::standard(3));
+        assert_eq!(err.unwrap_err(), "A and C need exactly one winner");
+
+        // A shape that beats everything leaves nothing to beat it with.
+        let rules = Rules::new(
+            &["A", "B", "C"],
+            &[(0, 1), (0, 2), (1, 2)],
+            Scoring::standard(3),
+        )
+        .unwrap();
+        assert_eq!(rules.respond(super::Shape(0), Outcome::Win), None);
+    }
+}
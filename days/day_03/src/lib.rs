@@ -0,0 +1,130 @@
+use std::{collections::HashSet, str::FromStr};
+
+use utils::{parse_lines, Answer, ParseError, Solution, SolveError};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(input).map(Answer::from)
+    }
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Result<i32, SolveError> {
+    rucksacks
+        .iter()
+        .map(|r| r.common_item().map(priority))
+        .sum()
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Result<i32, SolveError> {
+    if !rucksacks.len().is_multiple_of(3) {
+        let message = format!(
+            "{} rucksacks can't be split into groups of 3",
+            rucksacks.len()
+        );
+        return Err(SolveError::invariant(message));
+    }
+
+    rucksacks
+        .chunks(3)
+        .map(|group| Rucksack::badge(group).map(priority))
+        .sum()
+}
+
+pub struct Rucksack {
+    data: String,
+}
+
+impl Rucksack {
+    fn common_item(&self) -> Result<char, SolveError> {
+        let half_length = self.data.len() / 2;
+
+        let first_half = self.data[0..half_length].chars().collect::<HashSet<_>>();
+        let second_half = self.data[half_length..].chars().collect::<HashSet<_>>();
+
+        let common = first_half.intersection(&second_half).collect::<Vec<_>>();
+
+        match common[..] {
+            [item] => Ok(*item),
+            _ => Err(SolveError::invariant(format!(
+                "rucksack {} has {} item types in both compartments, expected 1",
+                self.data,
+                common.len()
+            ))),
+        }
+    }
+
+    fn badge(elves: &[Rucksack]) -> Result<char, SolveError> {
+        let common = elves
+            .iter()
+            .map(|r| r.data.chars().collect::<HashSet<_>>())
+            .reduce(|acc, item| acc.intersection(&item).cloned().collect::<HashSet<_>>())
+            .unwrap_or_default();
+
+        match common.iter().collect::<Vec<_>>()[..] {
+            [badge] => Ok(*badge),
+            _ => Err(SolveError::invariant(format!(
+                "a group of elves has {} item types in common, expected 1",
+                common.len()
+            ))),
+        }
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((column, c)) = s
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            return Err(ParseError::new("an item (a-z or A-Z)", c).at_column(column + 1));
+        }
+
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::new("an even number of items", s));
+        }
+
+        Ok(Rucksack { data: s.into() })
+    }
+}
+
+fn priority(c: char) -> i32 {
+    let is_upper = c.is_uppercase();
+    // lowercase z is 122, so find the negative offset and add it to 26.
+    let val = 26 + (c.to_ascii_lowercase() as i32 - 122);
+
+    if is_upper {
+        val + 26
+    } else {
+        val
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::priority;
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority('a'), 1);
+        assert_eq!(priority('z'), 26);
+        assert_eq!(priority('A'), 27);
+        assert_eq!(priority('Z'), 52);
+    }
+}
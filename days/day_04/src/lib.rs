@@ -0,0 +1,73 @@
+use std::str::FromStr;
+use utils::{parse_lines, parse_value, Answer, ParseError, Solution, SolveError};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|p| p.is_fully_overlapped()).count()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|p| p.overlaps()).count()
+}
+
+pub struct Pair {
+    first: (i32, i32),
+    second: (i32, i32),
+}
+
+impl Pair {
+    pub fn is_fully_overlapped(&self) -> bool {
+        (self.first.0 <= self.second.0 && self.first.1 >= self.second.1)
+            || (self.second.0 <= self.first.0 && self.second.1 >= self.first.1)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        (self.first.0 <= self.second.0 && self.second.0 <= self.first.1)
+            || (self.second.0 <= self.first.0 && self.first.0 <= self.second.1)
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::new("\"A-B,C-D\"", s))?;
+
+        Ok(Pair {
+            first: parse_range(first).map_err(|e| e.within(s, first))?,
+            second: parse_range(second).map_err(|e| e.within(s, second))?,
+        })
+    }
+}
+
+fn parse_range(s: &str) -> Result<(i32, i32), ParseError> {
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::new("a range \"A-B\"", s))?;
+
+    Ok((
+        parse_value(start, "a section number").map_err(|e| e.within(s, start))?,
+        parse_value(end, "a section number").map_err(|e| e.within(s, end))?,
+    ))
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use utils::{
+    parse_lines_with, parse_sections, parse_value, Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [stacks, instructions] = parse_sections(input)?;
+
+        let stacks = stacks.parse_with(Stacks::from_str)?;
+        let instructions = instructions.parse_with(|section| {
+            parse_lines_with(section, |line| {
+                let inst = Instruction::from_str(line)?;
+
+                // The stack numbers are the 4th and 6th words of "move N from A to B".
+                let numbers = line.split(' ').skip(3).step_by(2);
+                for (stack, number) in [inst.source, inst.target].into_iter().zip(numbers) {
+                    if stack == 0 || stack > stacks.stacks.len() {
+                        let expected = format!("a stack between 1 and {}", stacks.stacks.len());
+                        return Err(ParseError::new(expected, number).within(line, number));
+                    }
+                }
+
+                Ok(inst)
+            })
+        })?;
+
+        Ok((stacks, instructions))
+    }
+
+    fn part1((stacks, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        part1(stacks.clone(), instructions).map(Answer::from)
+    }
+
+    fn part2((stacks, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        part2(stacks.clone(), instructions).map(Answer::from)
+    }
+}
+
+pub fn part1(mut stacks: Stacks, insts: &[Instruction]) -> Result<String, SolveError> {
+    for inst in insts {
+        stacks.apply(inst)?;
+    }
+
+    Ok(stacks.arrangement())
+}
+
+pub fn part2(mut stacks: Stacks, insts: &[Instruction]) -> Result<String, SolveError> {
+    for inst in insts {
+        stacks.apply_batched(inst)?;
+    }
+
+    Ok(stacks.arrangement())
+}
+
+#[derive(Debug, Clone)]
+pub struct Stacks {
+    stacks: Vec<Vec<char>>,
+}
+
+impl Stacks {
+    pub fn apply(&mut self, inst: &Instruction) -> Result<(), SolveError> {
+        self.check_count(inst)?;
+
+        for _ in 0..inst.count {
+            if let Some(elem) = self.stacks[inst.source - 1].pop() {
+                self.stacks[inst.target - 1].push(elem);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn apply_batched(&mut self, inst: &Instruction) -> Result<(), SolveError> {
+        self.check_count(inst)?;
+
+        let point = self.stacks[inst.source - 1].len() - inst.count;
+        let mut to_move = self.stacks[inst.source - 1].split_off(point);
+        self.stacks[inst.target - 1].append(&mut to_move);
+
+        Ok(())
+    }
+
+    fn check_count(&self, inst: &Instruction) -> Result<(), SolveError> {
+        let available = self.stacks[inst.source - 1].len();
+
+        match inst.count > available {
+            true => Err(SolveError::invariant(format!(
+                "cannot move {} crates from stack {}, which only holds {available}",
+                inst.count, inst.source
+            ))),
+            false => Ok(()),
+        }
+    }
+
+    /// The crate on top of each stack, skipping any that ended up empty.
+    pub fn arrangement(&self) -> String {
+        self.stacks.iter().filter_map(|s| s.last()).collect()
+    }
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.split("\n").filter(|s| !s.is_empty()).collect::<Vec<_>>();
+        let (labels, crates) = lines
+            .split_last()
+            .ok_or_else(|| ParseError::new("a drawing of the stacks", s))?;
+        let num_stacks = labels.split_whitespace().count();
+
+        let mut stacks = (0..num_stacks).map(|_| vec![]).collect::<Vec<_>>();
+
+        for (line_idx, line) in crates.iter().enumerate() {
+            let chars = line.chars().collect::<Vec<_>>();
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                let idx = 1 + 4 * i;
+
+                match chars.get(idx) {
+                    Some(c @ 'A'..='Z') => stack.push(*c),
+                    Some(' ') | None => continue,
+                    Some(c) => {
+                        return Err(ParseError::new("a crate (A-Z)", *c)
+                            .at_line(line_idx + 1)
+                            .at_column(idx + 1))
+                    }
+                }
+            }
+        }
+
+        for stack in &mut stacks {
+            stack.reverse();
+        }
+
+        Ok(Stacks { stacks })
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    count: usize,
+    source: usize,
+    target: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static INST_RE: Lazy<Regex> =
+            Lazy::new(|| Regex::new("^move ([0-9]+) from ([0-9]+) to ([0-9]+)$").unwrap());
+
+        let captures = INST_RE
+            .captures(s)
+            .ok_or_else(|| ParseError::new("\"move N from A to B\"", s))?;
+        let number = |i| {
+            let m = captures.get(i).unwrap();
+            parse_value::<usize>(m.as_str(), "a number").map_err(|e| e.at_column(m.start() + 1))
+        };
+
+        let count = number(1)?;
+        let source = number(2)?;
+        let target = number(3)?;
+
+        Ok(Instruction {
+            count,
+            source,
+            target,
+        })
+    }
+}
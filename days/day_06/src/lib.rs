@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use utils::{Answer, ParseError, Solution, SolveError};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let data = input.trim_end();
+
+        match data.chars().position(|c| !c.is_ascii_lowercase()) {
+            Some(i) => Err(ParseError::new("a datastream of letters a-z", &data[i..])
+                .at_line(1)
+                .at_column(i + 1)),
+            None => Ok(data.to_owned()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(input).map(Answer::from)
+    }
+}
+
+pub fn part1(data: &str) -> Result<usize, SolveError> {
+    find_unique_sequence(data, 4)
+}
+
+pub fn part2(data: &str) -> Result<usize, SolveError> {
+    find_unique_sequence(data, 14)
+}
+
+/// The number of characters read by the end of the first `size` characters that all differ.
+fn find_unique_sequence(data: &str, size: usize) -> Result<usize, SolveError> {
+    data.as_bytes()
+        .windows(size)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == size)
+        .map(|start| start + size)
+        .ok_or_else(|| SolveError::no_solution(format!("no marker of {size} different characters")))
+}
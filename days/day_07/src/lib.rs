@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use utils::{lines, parse_value, Answer, ParseError, Solution, SolveError};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Dir;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(build_from_cmd(Cmd::parse_output(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(input).map(Answer::from)
+    }
+}
+
+pub fn part1(root: &Dir) -> usize {
+    let sizes = dir_sizes(root);
+    sizes.values().filter(|size| **size < 100000).sum()
+}
+
+pub fn part2(root: &Dir) -> Result<usize, SolveError> {
+    let sizes = dir_sizes(root);
+
+    let disk_size: usize = 70000000;
+    let required_unused_size: usize = 30000000;
+
+    let current_size: usize = root
+        .dirs
+        .iter()
+        .map(|d| sizes[&format!("/{}", d.name)])
+        .sum::<usize>()
+        + root.files.iter().map(|f| f.size).sum::<usize>();
+
+    let unused_size = disk_size.checked_sub(current_size).ok_or_else(|| {
+        SolveError::invariant(format!(
+            "the files take up {current_size}, more than the disk's {disk_size}"
+        ))
+    })?;
+    let difference = required_unused_size
+        .checked_sub(unused_size)
+        .filter(|difference| *difference > 0)
+        .ok_or(SolveError::no_solution(
+            "there is already enough unused space",
+        ))?;
+
+    sizes
+        .values()
+        .filter(|size| **size >= difference)
+        .min()
+        .copied()
+        .ok_or_else(|| {
+            SolveError::no_solution(format!("no directory is at least {difference} in size"))
+        })
+}
+
+/// Computes the total size of every directory below the root, keyed by its full path.
+fn dir_sizes(root: &Dir) -> HashMap<String, usize> {
+    let mut sizes: HashMap<String, usize> = HashMap::new();
+
+    fn traverse(parent: &str, dir: &Dir, sizes: &mut HashMap<String, usize>) -> usize {
+        let my_path = {
+            let mut p = parent.to_owned();
+            p.push('/');
+            p.push_str(&dir.name);
+            p
+        };
+
+        let file_size: usize = dir.files.iter().map(|f| f.size).sum();
+        let dir_size: usize = dir.dirs.iter().map(|d| traverse(&my_path, d, sizes)).sum();
+        let total = file_size + dir_size;
+
+        sizes.insert(my_path, total);
+        total
+    }
+
+    for child in &root.dirs {
+        traverse("", child, &mut sizes);
+    }
+
+    sizes
+}
+
+fn build_from_cmd(cmds: Vec<Cmd>) -> Dir {
+    let mut root = Dir::new("".into());
+
+    let mut current_path: String = "".into();
+
+    for cmd in &cmds[1..] {
+        match cmd {
+            Cmd::Cd(path) => {
+                if path == ".." {
+                    let parts = current_path.split("/").collect::<Vec<_>>();
+                    current_path = parts[0..(parts.len() - 1)].join("/");
+                } else {
+                    current_path.push('/');
+                    current_path.push_str(path);
+                }
+            }
+            Cmd::Ls(items) => {
+                let dir = root.traverse(&current_path);
+
+                for item in items {
+                    match item {
+                        Entry::Dir(name) => dir.dirs.push(Dir::new(name.clone())),
+                        Entry::File(size) => dir.files.push(File { size: *size }),
+                    }
+                }
+            }
+        }
+    }
+
+    root
+}
+
+#[derive(Debug)]
+pub struct Dir {
+    name: String,
+    dirs: Vec<Dir>,
+    files: Vec<File>,
+}
+
+impl Dir {
+    pub fn new(name: String) -> Dir {
+        Dir {
+            name,
+            dirs: vec![],
+            files: vec![],
+        }
+    }
+
+    pub fn traverse(&mut self, path: &str) -> &mut Dir {
+        let parts = path
+            .split("/")
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut current = self;
+
+        for part in parts {
+            let idx = match current.dirs.iter().position(|d| d.name == part) {
+                Some(idx) => idx,
+                None => {
+                    current.dirs.push(Dir::new(part.into()));
+                    current.dirs.len() - 1
+                }
+            };
+
+            current = &mut current.dirs[idx];
+        }
+
+        current
+    }
+}
+
+#[derive(Debug)]
+pub struct File {
+    size: usize,
+}
+
+#[derive(Debug)]
+enum Cmd {
+    Cd(String),
+    Ls(Vec<Entry>),
+}
+
+#[derive(Debug)]
+enum Entry {
+    Dir(String),
+    File(usize),
+}
+
+impl Cmd {
+    /// Parses the terminal session, which must start by changing to the root directory.
+    fn parse_output(output: &str) -> Result<Vec<Cmd>, ParseError> {
+        let mut cmds = vec![];
+
+        let mut iter = lines(output).filter(|(_, s)| !s.is_empty()).peekable();
+
+        match iter.peek() {
+            Some((_, "$ cd /")) => {}
+            Some((line_no, line)) => {
+                return Err(ParseError::new("\"$ cd /\"", *line).at_line(*line_no))
+            }
+            None => return Err(ParseError::new("a terminal session", output)),
+        }
+
+        while let Some((line_no, line)) = iter.next() {
+            match line.split(" ").collect::<Vec<_>>()[..] {
+                ["$", "ls"] => {
+                    let mut output = vec![];
+
+                    while let Some((line_no, item)) = iter.next_if(|(_, s)| !s.starts_with('$')) {
+                        let entry = Entry::parse(item).map_err(|e| e.at_line(line_no))?;
+                        output.push(entry);
+                    }
+
+                    cmds.push(Cmd::Ls(output));
+                }
+                ["$", "cd", path] => cmds.push(Cmd::Cd(path.into())),
+                _ => return Err(ParseError::new("\"$ cd DIR\" or \"$ ls\"", line).at_line(line_no)),
+            }
+        }
+
+        Ok(cmds)
+    }
+}
+
+impl Entry {
+    fn parse(item: &str) -> Result<Entry, ParseError> {
+        match item.split_once(' ') {
+            Some(("dir", name)) => Ok(Entry::Dir(name.into())),
+            Some((size, _)) => Ok(Entry::File(parse_value(size, "a file size")?)),
+            None => Err(ParseError::new("\"dir NAME\" or \"SIZE NAME\"", item)),
+        }
+    }
+}
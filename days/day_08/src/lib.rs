@@ -0,0 +1,67 @@
+use utils::{
+    geom::Direction,
+    grid::{Coords, Grid},
+    Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new("a tree height (0-9)", c))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
+
+pub fn part1(grid: &Grid<u32>) -> usize {
+    grid.coords().filter(|c| is_visible(*c, grid)).count()
+}
+
+pub fn part2(grid: &Grid<u32>) -> usize {
+    grid.coords()
+        .map(|coords| scenic_score(coords, grid))
+        .max()
+        .unwrap_or_default()
+}
+
+/// A tree is visible if every tree between it and some edge of the grid is shorter.
+fn is_visible(coords: Coords, grid: &Grid<u32>) -> bool {
+    let val = grid[coords];
+
+    Direction::ORTHOGONAL
+        .into_iter()
+        .any(|direction| grid.ray(coords, direction).all(|c| grid[c] < val))
+}
+
+fn scenic_score(coords: Coords, grid: &Grid<u32>) -> usize {
+    let val = grid[coords];
+
+    Direction::ORTHOGONAL
+        .into_iter()
+        .map(|direction| {
+            // Count every tree up to and including the first one that blocks the view.
+            let mut seen = 0;
+            for c in grid.ray(coords, direction) {
+                seen += 1;
+                if grid[c] >= val {
+                    break;
+                }
+            }
+            seen
+        })
+        .product()
+}
@@ -0,0 +1,186 @@
+use std::{collections::HashSet, str::FromStr};
+
+use utils::{
+    geom::{Direction, Point},
+    parse_lines, parse_value, Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Movement>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
+
+type Coords = Point<i32>;
+
+pub fn part1(movements: &[Movement]) -> usize {
+    let mut visited: HashSet<Coords> = HashSet::new();
+
+    let mut rope = Rope::new(2);
+
+    visited.insert(rope.tail_pos());
+
+    for movement in movements {
+        for _ in 0..movement.magnitude {
+            rope.apply(movement.direction);
+            visited.insert(rope.tail_pos());
+        }
+    }
+
+    visited.len()
+}
+
+pub fn part2(movements: &[Movement]) -> usize {
+    let mut visited: HashSet<Coords> = HashSet::new();
+
+    let mut rope = Rope::new(10);
+
+    visited.insert(rope.tail_pos());
+
+    for movement in movements {
+        for _ in 0..movement.magnitude {
+            rope.apply(movement.direction);
+            visited.insert(rope.tail_pos());
+        }
+    }
+
+    visited.len()
+}
+
+#[derive(Debug)]
+struct Rope {
+    head: Coords,
+    /// Every knot behind the head, ending with the tail.
+    knots: Vec<Coords>,
+}
+
+impl Rope {
+    pub fn new(size: usize) -> Rope {
+        Rope {
+            head: Point::default(),
+            knots: vec![Point::default(); size.saturating_sub(1)],
+        }
+    }
+
+    pub fn apply(&mut self, dir: Direction) {
+        self.head += dir.as_offset();
+
+        let mut leader = self.head;
+        for follower in &mut self.knots {
+            // Once a knot is no longer touching the one ahead, it moves one step towards it,
+            // diagonally if they aren't in the same row or column.
+            if leader.chebyshev(*follower) > 1 {
+                *follower += (leader - *follower).signum();
+            }
+
+            leader = *follower;
+        }
+    }
+
+    /// The last knot, which is the head itself for a rope of a single knot.
+    pub fn tail_pos(&self) -> Coords {
+        self.knots.last().copied().unwrap_or(self.head)
+    }
+}
+
+pub struct Movement {
+    direction: Direction,
+    magnitude: usize,
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, magnitude) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new("\"<L|R|U|D> N\"", s))?;
+
+        let direction = match direction {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => return Err(ParseError::new("a direction (L, R, U or D)", direction).at_column(1)),
+        };
+
+        let magnitude =
+            parse_value(magnitude, "a number of steps").map_err(|e| e.within(s, magnitude))?;
+
+        Ok(Movement {
+            direction,
+            magnitude,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+    use utils::{gen, Solution};
+
+    use crate::{part1, part2, Day09};
+
+    /// Moves every knot one step at a time, spelling out the puzzle's rules case by case.
+    fn reference(input: &str, knots: usize) -> usize {
+        let mut rope = vec![(0i32, 0i32); knots];
+        let mut visited = HashSet::from([(0, 0)]);
+
+        for line in input.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+
+            for _ in 0..steps.parse::<u32>().unwrap() {
+                match direction {
+                    "L" => rope[0].0 -= 1,
+                    "R" => rope[0].0 += 1,
+                    "U" => rope[0].1 -= 1,
+                    _ => rope[0].1 += 1,
+                }
+
+                for i in 1..knots {
+                    let (dx, dy) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                    if dx.abs() <= 1 && dy.abs() <= 1 {
+                        continue;
+                    }
+
+                    if dx != 0 {
+                        rope[i].0 += dx / dx.abs();
+                    }
+                    if dy != 0 {
+                        rope[i].1 += dy / dy.abs();
+                    }
+                }
+
+                visited.insert(rope[knots - 1]);
+            }
+        }
+
+        visited.len()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_reference(input in gen::rope_moves()) {
+            let movements = Day09::parse(&input).unwrap();
+
+            prop_assert_eq!(part1(&movements), reference(&input, 2));
+            prop_assert_eq!(part2(&movements), reference(&input, 10));
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::str::FromStr;
+use utils::{
+    geom::Point, grid::Grid, ocr, parse_lines, parse_value, trace, Answer, ParseError, Solution,
+    SolveError,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let screen = part2(input);
+
+        // Inputs that don't spell anything, like the example, still get to show their picture,
+        // but why it couldn't be read is kept for `--stats` in case it should have been.
+        match ocr::recognise(&screen) {
+            Ok(letters) => Ok(letters.into()),
+            Err(err) => {
+                trace::event("unreadable screen", || err.to_string());
+                Ok(screen.map(|lit| if *lit { '■' } else { ' ' }).into())
+            }
+        }
+    }
+}
+
+/// The cycles whose signal strengths part 1 adds up.
+const SAMPLED_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+pub fn part1(insts: &[Instruction]) -> i32 {
+    let mut machine = Machine::new();
+    let cycles = machine.apply(insts);
+
+    SAMPLED_CYCLES
+        .iter()
+        .filter_map(|cycle| cycles.get(cycle - 1).map(|x| x * *cycle as i32))
+        .sum()
+}
+
+/// Renders the CRT output as a 40x6 picture, with lit pixels set.
+pub fn part2(insts: &[Instruction]) -> Grid<bool> {
+    let mut machine = Machine::new();
+    let cycles = machine.apply(insts);
+
+    // A program that stops early leaves the rest of the screen dark.
+    Grid::from_fn(40, 6, |Point { x, y }| {
+        let column = x as i32;
+
+        cycles
+            .get(40 * y + x)
+            .is_some_and(|sprite| (column - 1..=column + 1).contains(sprite))
+    })
+}
+
+struct Machine {
+    register: i32,
+}
+
+impl Machine {
+    pub fn new() -> Machine {
+        Machine { register: 1 }
+    }
+
+    pub fn apply(&mut self, insts: &[Instruction]) -> Vec<i32> {
+        let mut cycles = vec![];
+
+        for inst in insts {
+            match inst {
+                Instruction::NoOp => cycles.push(self.register),
+                Instruction::AddX(val) => {
+                    cycles.push(self.register);
+                    cycles.push(self.register);
+                    self.register += val;
+                }
+            }
+        }
+
+        cycles
+    }
+}
+
+pub enum Instruction {
+    NoOp,
+    AddX(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inst = match s.split_once(' ') {
+            None if s == "noop" => Instruction::NoOp,
+            Some(("addx", value)) => {
+                Instruction::AddX(parse_value(value, "an integer").map_err(|e| e.within(s, value))?)
+            }
+            _ => return Err(ParseError::new("\"noop\" or \"addx V\"", s)),
+        };
+
+        Ok(inst)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use utils::{gen, trace, Answer, Solution};
+
+    use crate::{part1, part2, Day10};
+
+    #[test]
+    fn test_unreadable_screen() {
+        let program = Day10::parse(include_str!("../../../inputs/examples/day_10.txt")).unwrap();
+        trace::take();
+
+        let Ok(Answer::Grid(rows)) = Day10::part2(&program) else {
+            panic!("expected the screen's picture");
+        };
+        assert_eq!(rows[0], "■■  ■■  ■■  ■■  ■■  ■■  ■■  ■■  ■■  ■■  ");
+
+        if trace::ENABLED {
+            let events = trace::take().events;
+            assert_eq!(
+                events,
+                [(
+                    "unreadable screen",
+                    "unknown letter starting at column 0".to_string()
+                )]
+            );
+        }
+    }
+
+    /// The value of X during every cycle, worked out separately for each cycle from the `addx`
+    /// instructions that finished before it.
+    fn reference(input: &str) -> Vec<i32> {
+        let mut finished = vec![];
+        let mut cycle = 0;
+
+        for line in input.lines() {
+            match line.strip_prefix("addx ") {
+                Some(value) => {
+                    cycle += 2;
+                    finished.push((cycle, value.parse::<i32>().unwrap()));
+                }
+                None => cycle += 1,
+            }
+        }
+
+        (1..=cycle)
+            .map(|during| {
+                let added = finished.iter().filter(|(at, _)| *at < during);
+                1 + added.map(|(_, value)| value).sum::<i32>()
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_reference(input in gen::crt_program()) {
+            let program = Day10::parse(&input).unwrap();
+            let x = reference(&input);
+
+            let strength = [20, 60, 100, 140, 180, 220].map(|cycle| cycle as i32 * x[cycle - 1]);
+            prop_assert_eq!(part1(&program), strength.iter().sum::<i32>());
+
+            let screen = part2(&program);
+            for (pixel, lit) in screen.iter() {
+                let column = pixel.x as i32;
+                let sprite = x[40 * pixel.y + pixel.x];
+                prop_assert_eq!(*lit, (sprite - column).abs() <= 1, "pixel {:?}", pixel);
+            }
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use std::{
+    str::FromStr,
+    sync::mpsc::{channel, Receiver, Sender},
+};
+
+use utils::{parse_value, sections, Answer, OverflowError, ParseError, Solution, SolveError};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let sections = sections(input);
+        let monkeys = sections
+            .iter()
+            .map(|section| section.parse_with(Monkey::from_str))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (number, (section, monkey)) in sections.iter().zip(&monkeys).enumerate() {
+            for (target, index) in [(monkey.iftrue, 4), (monkey.iffalse, 5)] {
+                // A monkey throwing to itself would keep catching the same item forever.
+                let expected = match target {
+                    _ if target >= monkeys.len() => {
+                        format!("a monkey between 0 and {}", monkeys.len() - 1)
+                    }
+                    _ if target == number => format!("a monkey other than {number}"),
+                    _ => continue,
+                };
+
+                let err = ParseError::new(expected, target.to_string());
+                return Err(err.at_line(section.line + index));
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(input).map(Answer::from)
+    }
+}
+
+pub fn part1(monkeys: &[Monkey]) -> Result<usize, SolveError> {
+    let mut monkeys = monkeys.to_vec();
+    let channels = generate_channels(&monkeys);
+
+    for _ in 0..20 {
+        do_round(&mut monkeys, &channels, Relief::Divide(3))?;
+    }
+
+    monkey_business(&monkeys)
+}
+
+pub fn part2(monkeys: &[Monkey]) -> Result<usize, SolveError> {
+    let mut monkeys = monkeys.to_vec();
+    let channels = generate_channels(&monkeys);
+
+    // Every test is a divisibility check, so worry levels can be kept modulo the product of
+    // all divisors without changing the outcome of any test.
+    let modulus = monkeys
+        .iter()
+        .try_fold(1i64, |product, m| product.checked_mul(m.divisor))
+        .ok_or_else(|| overflow("the product of every monkey's divisor"))?;
+    let relief = Relief::Modulo(modulus);
+
+    for _ in 0..10000 {
+        do_round(&mut monkeys, &channels, relief)?;
+    }
+
+    monkey_business(&monkeys)
+}
+
+/// The product of the two highest inspection counts.
+fn monkey_business(monkeys: &[Monkey]) -> Result<usize, SolveError> {
+    if monkeys.len() < 2 {
+        let message = format!("need at least 2 monkeys, found {}", monkeys.len());
+        return Err(SolveError::no_solution(message));
+    }
+
+    let mut inspections: Vec<usize> = monkeys.iter().map(|m| m.inspections).collect();
+    inspections.sort();
+    inspections.reverse();
+
+    Ok(inspections[0..=1].iter().product())
+}
+
+fn do_round(
+    monkeys: &mut [Monkey],
+    channels: &[(Sender<i64>, Receiver<i64>)],
+    relief: Relief,
+) -> Result<(), SolveError> {
+    for (idx, monkey) in monkeys.iter_mut().enumerate() {
+        let recv = &channels[idx].1;
+
+        while let Some(thrown) = monkey.inspect(recv, relief) {
+            let (target_idx, item) = thrown.ok_or_else(|| {
+                overflow(&format!("the worry level of an item monkey {idx} inspects"))
+            })?;
+
+            channels[target_idx].0.send(item).map_err(|_| {
+                SolveError::invariant(format!("monkey {target_idx} stopped catching items"))
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Worry levels are kept in `i64`, which is plenty for the puzzle but not for any input.
+fn overflow(what: &str) -> SolveError {
+    SolveError::Overflow(OverflowError {
+        what: what.to_owned(),
+        line: None,
+        width: "i64",
+        hint: None,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    starting_items: Vec<i64>,
+    operation: Operation,
+    divisor: i64,
+    iftrue: usize,
+    iffalse: usize,
+
+    inspections: usize,
+}
+
+impl Monkey {
+    /// Inspects the next item, if there is one, giving the monkey it's thrown to and its new worry
+    /// level, or `None` for those if the worry level no longer fits in an `i64`.
+    pub fn inspect(&mut self, rx: &Receiver<i64>, relief: Relief) -> Option<Option<(usize, i64)>> {
+        let item = rx.try_recv().ok()?;
+        self.inspections += 1;
+
+        let Some(item) = self
+            .operation
+            .apply(item)
+            .and_then(|item| relief.apply(item))
+        else {
+            return Some(None);
+        };
+
+        if item % self.divisor == 0 {
+            Some(Some((self.iftrue, item)))
+        } else {
+            Some(Some((self.iffalse, item)))
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let (items, at) = field(&lines, 1, "Starting items: ")?;
+        let starting_items = items
+            .split(", ")
+            .map(|item| parse_value(item, "a worry level").map_err(|e| e.within(items, item)))
+            .collect::<Result<_, _>>()
+            .map_err(at)?;
+
+        let (operation, at) = field(&lines, 2, "Operation: new = ")?;
+        let operation = Operation::from_str(operation).map_err(at)?;
+
+        let (divisor, at) = field(&lines, 3, "Test: divisible by ")?;
+        let divisor = match parse_value(divisor, "a divisor").map_err(&at)? {
+            0 => return Err(at(ParseError::new("a non-zero divisor", divisor))),
+            divisor => divisor,
+        };
+
+        let (iftrue, at) = field(&lines, 4, "If true: throw to monkey ")?;
+        let iftrue = parse_value(iftrue, "a monkey number").map_err(at)?;
+
+        let (iffalse, at) = field(&lines, 5, "If false: throw to monkey ")?;
+        let iffalse = parse_value(iffalse, "a monkey number").map_err(at)?;
+
+        Ok(Monkey {
+            starting_items,
+            operation,
+            divisor,
+            iftrue,
+            iffalse,
+            inspections: 0,
+        })
+    }
+}
+
+/// Finds the value following `prefix` on the given line of a monkey's description, along with a
+/// function that points errors in that value back at its line and column.
+fn field<'a>(
+    lines: &[&'a str],
+    index: usize,
+    prefix: &str,
+) -> Result<(&'a str, impl Fn(ParseError) -> ParseError + 'a), ParseError> {
+    let line = lines.get(index).copied().unwrap_or_default();
+    let value = line.trim().strip_prefix(prefix).ok_or_else(|| {
+        ParseError::new(format!("\"{prefix}...\""), line.trim()).at_line(index + 1)
+    })?;
+
+    Ok((value, move |e: ParseError| {
+        e.within(line, value).at_line(index + 1)
+    }))
+}
+
+/// How worry levels are kept in check after each inspection.
+#[derive(Debug, Clone, Copy)]
+pub enum Relief {
+    /// Part 1: worry drops by this factor once the monkey loses interest in an item.
+    Divide(i64),
+    /// Part 2: there is no relief, so worry is kept modulo the given value instead.
+    Modulo(i64),
+}
+
+impl Relief {
+    /// The relieved worry level, or `None` for the one division an `i64` can't hold the result of.
+    fn apply(&self, item: i64) -> Option<i64> {
+        match self {
+            Relief::Divide(val) => item.checked_div(*val),
+            Relief::Modulo(val) => item.checked_rem(*val),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(i64),
+    Multiply(i64),
+    Square,
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it doesn't fit in an `i64`.
+    fn apply(&self, old: i64) -> Option<i64> {
+        match self {
+            Operation::Add(val) => old.checked_add(*val),
+            Operation::Multiply(val) => old.checked_mul(*val),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let operand = |val| parse_value(val, "a number or \"old\"").map_err(|e| e.within(s, val));
+
+        match s.split(" ").collect::<Vec<_>>()[..] {
+            ["old", "*", "old"] => Ok(Operation::Square),
+            ["old", "*", val] => Ok(Operation::Multiply(operand(val)?)),
+            ["old", "+", val] => Ok(Operation::Add(operand(val)?)),
+            _ => Err(ParseError::new("\"old <*|+> V\"", s)),
+        }
+    }
+}
+
+fn generate_channels(monkeys: &[Monkey]) -> Vec<(Sender<i64>, Receiver<i64>)> {
+    monkeys
+        .iter()
+        .map(|monkey| {
+            let (tx, rx) = channel();
+
+            for val in &monkey.starting_items {
+                tx.send(*val).unwrap();
+            }
+
+            (tx, rx)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use utils::{Solution, SolveError};
+
+    use crate::{monkey_business, part1, part2, Day11, Monkey, Relief};
+
+    const EXAMPLE: &str = include_str!("../../../inputs/examples/day_11.txt");
+
+    #[test]
+    fn test_relief() {
+        let monkeys = Day11::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Relief::Divide(3).apply(1501), Some(500));
+        assert_eq!(Relief::Modulo(96577).apply(96578), Some(1));
+        assert_eq!(Relief::Divide(-1).apply(i64::MIN), None);
+
+        // Dividing by 3 is what keeps part 1's worry levels, and so its answer, small.
+        assert_eq!(part1(&monkeys), Ok(10605));
+        assert_eq!(part2(&monkeys), Ok(2713310158));
+    }
+
+    #[test]
+    fn test_too_few_monkeys() {
+        let monkey = Monkey::from_str(EXAMPLE.split("\n\n").next().unwrap()).unwrap();
+
+        assert!(matches!(
+            monkey_business(&[]),
+            Err(SolveError::NoSolution(_))
+        ));
+        assert_eq!(
+            monkey_business(&[monkey]),
+            Err(SolveError::no_solution("need at least 2 monkeys, found 1"))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = EXAMPLE.replacen("old * 19", "old * 9223372036854775807", 1);
+        let monkeys = Day11::parse(&input).unwrap();
+        assert!(matches!(part1(&monkeys), Err(SolveError::Overflow(_))));
+
+        let input = EXAMPLE.replacen("divisible by 23", "divisible by 9223372036854775807", 1);
+        let Err(SolveError::Overflow(err)) = part2(&Day11::parse(&input).unwrap()) else {
+            panic!("expected an overflow");
+        };
+        assert_eq!(err.what, "the product of every monkey's divisor");
+    }
+
+    #[test]
+    fn test_throwing_to_itself() {
+        let input = EXAMPLE.replacen(
+            "If true: throw to monkey 2",
+            "If true: throw to monkey 0",
+            1,
+        );
+        let err = Day11::parse(&input).unwrap_err();
+
+        assert_eq!(err.expected, "a monkey other than 0");
+        assert_eq!(err.line, Some(5));
+    }
+}
@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use utils::{
+    grid::{Coords, Grid},
+    search::{bfs, bfs_from},
+    Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Heightmap::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        part1(input)
+            .map(Answer::from)
+            .ok_or(SolveError::no_solution(
+                "the best signal position is unreachable",
+            ))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(input)
+            .map(Answer::from)
+            .ok_or(SolveError::no_solution(
+                "the best signal position is unreachable",
+            ))
+    }
+}
+
+pub fn part1(hm: &Heightmap) -> Option<usize> {
+    bfs(hm.start, |c| hm.climbable(*c)).cost(&hm.end)
+}
+
+/// Searches from every square at the lowest elevation at once, so the distance to the end is the
+/// one from whichever of them is closest.
+pub fn part2(hm: &Heightmap) -> Option<usize> {
+    let lowest = hm
+        .heights
+        .iter()
+        .filter(|(_, height)| **height == 1)
+        .map(|(c, _)| c);
+
+    bfs_from(lowest, |c| hm.climbable(*c)).cost(&hm.end)
+}
+
+#[derive(Debug)]
+pub struct Heightmap {
+    heights: Grid<usize>,
+    start: Coords,
+    end: Coords,
+}
+
+impl Heightmap {
+    /// The neighbouring squares that are at most one higher than `coords`.
+    fn climbable(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        let max = self.heights[coords] + 1;
+
+        self.heights
+            .neighbours4(coords)
+            .filter(move |c| self.heights[*c] <= max)
+    }
+}
+
+impl FromStr for Heightmap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse_with(s, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            _ => Err(ParseError::new("a height (a-z), S or E", c)),
+        })?;
+
+        let start = map
+            .position(|c| *c == 'S')
+            .ok_or_else(|| ParseError::new("a start position S", s))?;
+        let end = map
+            .position(|c| *c == 'E')
+            .ok_or_else(|| ParseError::new("a best signal position E", s))?;
+
+        let heights = map.map(|c| match c {
+            'S' => 1,
+            'E' => 26,
+            c => (*c as u8 - b'a') as usize + 1,
+        });
+
+        Ok(Heightmap {
+            heights,
+            start,
+            end,
+        })
+    }
+}
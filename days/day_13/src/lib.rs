@@ -0,0 +1,254 @@
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
+
+use utils::{
+    parse_groups, parse_lines_with, parse_value, Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Sequence, Sequence)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_groups(input, |group| {
+            let mut packets = parse_lines_with(group, Sequence::parse)?;
+
+            match (packets.pop(), packets.pop(), packets.is_empty()) {
+                (Some(right), Some(left), true) => Ok((left, right)),
+                _ => Err(ParseError::new("a pair of packets", group)),
+            }
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let sequences = input
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect();
+
+        Ok(part2(sequences).into())
+    }
+}
+
+pub fn part1(pairs: &[(Sequence, Sequence)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left < right)
+        .map(|(idx, _)| idx + 1)
+        .sum()
+}
+
+pub fn part2(mut sequences: Vec<Sequence>) -> usize {
+    let dividers = vec![
+        Sequence::List(vec![Sequence::List(vec![Sequence::Val(2)])]),
+        Sequence::List(vec![Sequence::List(vec![Sequence::Val(6)])]),
+    ];
+
+    sequences.append(&mut dividers.clone());
+    sequences.sort();
+
+    dividers
+        .iter()
+        .map(|d| sequences.iter().position(|e| e == d).unwrap() + 1)
+        .product()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sequence {
+    Val(u32),
+    List(Vec<Sequence>),
+}
+
+impl Sequence {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut chars = s.char_indices().peekable();
+        let sequence = Sequence::parse_list(s, &mut chars)?;
+
+        match chars.next() {
+            Some((i, _)) => Err(ParseError::new("the end of the packet", &s[i..]).at_column(i + 1)),
+            None => Ok(sequence),
+        }
+    }
+
+    fn parse_list(s: &str, chars: &mut Peekable<CharIndices>) -> Result<Self, ParseError> {
+        let mut elems = vec![];
+
+        Sequence::expect(s, chars, &['['])?;
+        if chars.next_if(|(_, c)| *c == ']').is_some() {
+            return Ok(Sequence::List(elems));
+        }
+
+        loop {
+            elems.push(Sequence::parse_elem(s, chars)?);
+
+            if Sequence::expect(s, chars, &[',', ']'])? == ']' {
+                return Ok(Sequence::List(elems));
+            }
+        }
+    }
+
+    fn parse_elem(s: &str, chars: &mut Peekable<CharIndices>) -> Result<Self, ParseError> {
+        match chars.peek() {
+            Some((_, '[')) => Sequence::parse_list(s, chars),
+            Some((start, '0'..='9')) => {
+                let start = *start;
+                let mut end = start;
+                while let Some((i, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = i + c.len_utf8();
+                }
+
+                parse_value(&s[start..end], "a number")
+                    .map(Sequence::Val)
+                    .map_err(|e| e.at_column(start + 1))
+            }
+            Some((i, _)) => Err(ParseError::new("a number or \"[\"", &s[*i..]).at_column(i + 1)),
+            None => Err(ParseError::new("a number or \"[\"", "").at_column(s.len() + 1)),
+        }
+    }
+
+    /// Consumes the next character, which must be one of `allowed`.
+    fn expect(
+        s: &str,
+        chars: &mut Peekable<CharIndices>,
+        allowed: &[char],
+    ) -> Result<char, ParseError> {
+        let expected = || {
+            let quoted = allowed
+                .iter()
+                .map(|c| format!("\"{c}\""))
+                .collect::<Vec<_>>();
+            quoted.join(" or ")
+        };
+
+        match chars.next() {
+            Some((_, c)) if allowed.contains(&c) => Ok(c),
+            Some((i, _)) => Err(ParseError::new(expected(), &s[i..]).at_column(i + 1)),
+            None => Err(ParseError::new(expected(), "").at_column(s.len() + 1)),
+        }
+    }
+
+    pub fn as_list(self) -> Sequence {
+        match self {
+            Sequence::Val(_) => Sequence::List(vec![self]),
+            Sequence::List(_) => self,
+        }
+    }
+}
+
+impl PartialOrd for Sequence {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Sequence {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Sequence::Val(val), Sequence::Val(other_val)) => val.cmp(other_val),
+            (Sequence::Val(_), Sequence::List(_)) => self.clone().as_list().cmp(other),
+            (Sequence::List(_), Sequence::Val(_)) => self.cmp(&other.clone().as_list()),
+            (Sequence::List(vals), Sequence::List(other_vals)) => {
+                if other_vals.is_empty() && !vals.is_empty() {
+                    return Ordering::Greater;
+                }
+
+                for idx in 0..vals.len() {
+                    if idx > other_vals.len() - 1 {
+                        return Ordering::Greater;
+                    }
+
+                    let val = &vals[idx];
+                    let other_val = &other_vals[idx];
+
+                    match val.cmp(other_val) {
+                        Ordering::Equal => continue,
+                        x => return x,
+                    }
+                }
+
+                if other_vals.len() > vals.len() {
+                    return Ordering::Less;
+                }
+
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use proptest::prelude::*;
+    use utils::{gen, Solution};
+
+    use crate::{part1, part2, Day13, Sequence};
+
+    /// Compares packets by following the puzzle's rules as literally as possible.
+    fn reference(left: &Sequence, right: &Sequence) -> Ordering {
+        match (left, right) {
+            (Sequence::Val(left), Sequence::Val(right)) => left.cmp(right),
+            (Sequence::Val(_), Sequence::List(_)) => {
+                reference(&Sequence::List(vec![left.clone()]), right)
+            }
+            (Sequence::List(_), Sequence::Val(_)) => {
+                reference(left, &Sequence::List(vec![right.clone()]))
+            }
+            (Sequence::List(lefts), Sequence::List(rights)) => lefts
+                .iter()
+                .zip(rights)
+                .map(|(left, right)| reference(left, right))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(lefts.len().cmp(&rights.len())),
+        }
+    }
+
+    fn divider(value: u32) -> Sequence {
+        Sequence::List(vec![Sequence::List(vec![Sequence::Val(value)])])
+    }
+
+    proptest! {
+        #[test]
+        fn test_packet_order(left in gen::packet(), right in gen::packet()) {
+            let (left, right) = (Sequence::parse(&left).unwrap(), Sequence::parse(&right).unwrap());
+
+            prop_assert_eq!(left.cmp(&right), reference(&left, &right));
+        }
+
+        #[test]
+        fn test_matches_reference(input in gen::packet_pairs()) {
+            let pairs = Day13::parse(&input).unwrap();
+            let packets = pairs.iter().flat_map(|(left, right)| [left, right]).collect::<Vec<_>>();
+
+            // Where a packet sorts the same as a divider, the divider's position depends on how
+            // ties are broken, which the puzzle leaves open.
+            let dividers = [divider(2), divider(6)];
+            prop_assume!(packets
+                .iter()
+                .all(|p| dividers.iter().all(|d| reference(p, d).is_ne())));
+
+            let ordered = pairs
+                .iter()
+                .enumerate()
+                .filter(|(_, (left, right))| reference(left, right).is_lt())
+                .map(|(idx, _)| idx + 1)
+                .sum::<usize>();
+            prop_assert_eq!(part1(&pairs), ordered);
+
+            // Each divider ends up after every packet that sorts before it, and the second one
+            // after the first as well.
+            let before = |d: &Sequence| packets.iter().filter(|p| reference(p, d).is_lt()).count();
+            let key = (before(&dividers[0]) + 1) * (before(&dividers[1]) + 2);
+            let sequences = packets.into_iter().cloned().collect();
+            prop_assert_eq!(part2(sequences), key);
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::str::FromStr;
+
+use utils::{
+    geom::{Direction, Point},
+    grid::Grid,
+    parse_lines, parse_value, trace, Answer, ParseError, Solution, SolveError,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Cave::from_scans(parse_lines(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input.clone()).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(input.clone()).into())
+    }
+}
+
+pub fn part1(mut cave: Cave) -> usize {
+    let mut units = 0;
+
+    while cave.drop_sand().is_some() {
+        units += 1;
+    }
+
+    units
+}
+
+pub fn part2(mut cave: Cave) -> usize {
+    let mut units = 0;
+
+    loop {
+        units += 1;
+
+        if cave.drop_sand_with_floor() == SOURCE {
+            return units;
+        }
+    }
+}
+
+/// A position in the cave, with `y` growing downwards from the top.
+type Coords = Point<usize>;
+
+/// Where the sand pours in from.
+const SOURCE: Coords = Point::new(500, 0);
+
+#[derive(Debug)]
+pub struct Scan {
+    parts: Vec<Coords>,
+}
+
+impl FromStr for Scan {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .split(" -> ")
+            .map(|coords| {
+                let (x, y) = coords
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::new("a point \"X,Y\"", coords).within(s, coords))?;
+
+                Ok(Point::new(
+                    parse_value(x, "a coordinate").map_err(|e| e.within(s, x))?,
+                    parse_value(y, "a coordinate").map_err(|e| e.within(s, y))?,
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Scan { parts })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    /// Covers every rock and every position sand can reach, shifted left by `x_offset`.
+    occupied: Grid<bool>,
+    x_offset: usize,
+    abyss_limit: usize,
+    floor: usize,
+}
+
+impl Cave {
+    pub fn from_scans(scans: Vec<Scan>) -> Self {
+        let points = scans.iter().flat_map(|scan| &scan.parts);
+        let abyss_limit = points.clone().map(|c| c.y).max().unwrap_or_default();
+        let floor = abyss_limit + 2;
+
+        // Sand spreads at most one step sideways for every step down, so it never gets further
+        // than `floor` from the source.
+        let min_x = points.clone().map(|c| c.x).min().unwrap_or(SOURCE.x);
+        let max_x = points.map(|c| c.x).max().unwrap_or(SOURCE.x);
+        let x_offset = min_x.min(SOURCE.x.saturating_sub(floor)).saturating_sub(1);
+        let width = max_x.max(SOURCE.x + floor) + 2 - x_offset;
+
+        let mut cave = Cave {
+            occupied: Grid::new(width, floor + 1, false),
+            x_offset,
+            abyss_limit,
+            floor,
+        };
+
+        for scan in scans {
+            for pair in scan.parts.windows(2) {
+                let (start, end) = (pair[0], pair[1]);
+
+                for x in start.x.min(end.x)..=start.x.max(end.x) {
+                    for y in start.y.min(end.y)..=start.y.max(end.y) {
+                        cave.fill(Point::new(x, y));
+                    }
+                }
+            }
+        }
+
+        cave
+    }
+
+    fn is_occupied(&self, position: Coords) -> bool {
+        self.occupied[position - Point::new(self.x_offset, 0)]
+    }
+
+    fn fill(&mut self, position: Coords) {
+        self.occupied[position - Point::new(self.x_offset, 0)] = true;
+    }
+
+    /// Where sand at `position` moves next: straight down if possible, otherwise diagonally down
+    /// and to the left, otherwise down and to the right, or `None` if it comes to rest.
+    fn next_position(&self, position: Coords) -> Option<Coords> {
+        [Direction::Down, Direction::DownLeft, Direction::DownRight]
+            .into_iter()
+            .filter_map(|direction| position.checked_offset(direction.as_offset()))
+            .find(|next| !self.is_occupied(*next))
+    }
+
+    /// Drops a unit of sand, returning where it came to rest or `None` if it fell into the abyss
+    /// or the sand has piled up all the way to the source.
+    pub fn drop_sand(&mut self) -> Option<Coords> {
+        if self.is_occupied(SOURCE) {
+            return None;
+        }
+
+        trace::count("sand units dropped");
+        let mut position = SOURCE;
+
+        while let Some(next) = self.next_position(position) {
+            if next.y > self.abyss_limit {
+                trace::event("abyss", || {
+                    format!("sand fell past row {}", self.abyss_limit)
+                });
+                return None;
+            }
+
+            position = next;
+        }
+
+        self.fill(position);
+        Some(position)
+    }
+
+    /// Drops a unit of sand onto the floor two rows below the lowest rock, returning where it came
+    /// to rest.
+    pub fn drop_sand_with_floor(&mut self) -> Coords {
+        trace::count("sand units dropped");
+        let mut position = SOURCE;
+
+        while position.y + 1 < self.floor {
+            match self.next_position(position) {
+                Some(next) => position = next,
+                None => break,
+            }
+        }
+
+        self.fill(position);
+        position
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+    use utils::{gen, Solution};
+
+    use crate::{part1, part2, Day14};
+
+    /// Drops sand one unit at a time into a set of blocked positions, without bounding the cave.
+    fn reference(input: &str, with_floor: bool) -> usize {
+        let mut blocked = HashSet::new();
+        for line in input.lines() {
+            let points = line
+                .split(" -> ")
+                .map(|point| {
+                    let (x, y) = point.split_once(',').unwrap();
+                    (x.parse::<usize>().unwrap(), y.parse::<usize>().unwrap())
+                })
+                .collect::<Vec<_>>();
+
+            for pair in points.windows(2) {
+                let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        blocked.insert((x, y));
+                    }
+                }
+            }
+        }
+
+        let lowest = blocked.iter().map(|(_, y)| *y).max().unwrap();
+        let rocks = blocked.len();
+
+        while !blocked.contains(&(500, 0)) {
+            let (mut x, mut y) = (500, 0);
+
+            loop {
+                if with_floor && y + 1 == lowest + 2 {
+                    break;
+                }
+                if !with_floor && y > lowest {
+                    return blocked.len() - rocks;
+                }
+
+                match [x, x - 1, x + 1]
+                    .into_iter()
+                    .find(|x| !blocked.contains(&(*x, y + 1)))
+                {
+                    Some(next) => (x, y) = (next, y + 1),
+                    None => break,
+                }
+            }
+
+            blocked.insert((x, y));
+        }
+
+        blocked.len() - rocks
+    }
+
+    #[test]
+    fn test_sand_reaches_source() {
+        // The first unit comes to rest on the source itself, which used to leave every later unit
+        // resting there too, so part 1 never finished.
+        let cave = Day14::parse("499,1 -> 501,1").unwrap();
+
+        assert_eq!(part1(cave.clone()), 1);
+        assert_eq!(part2(cave), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_reference(input in gen::rock_paths()) {
+            let cave = Day14::parse(&input).unwrap();
+
+            prop_assert_eq!(part1(cave.clone()), reference(&input, false));
+            prop_assert_eq!(part2(cave), reference(&input, true));
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use utils::{
+    geom::Point, parse_lines, parse_value, Answer, Params, ParseError, Solution, SolveError,
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Survey;
+
+    const PARAMS: &'static [&'static str] = &["row", "max"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Survey::parse(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, SolveError> {
+        Ok(Survey::parse(input, params)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(&input.sensors, input.row).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part2(&input.sensors, input.max)
+            .map(Answer::from)
+            .ok_or(SolveError::no_solution(
+                "no gap found in the sensor coverage",
+            ))
+    }
+}
+
+/// The sensors along with the puzzle parameters that apply to them.
+#[derive(Debug)]
+pub struct Survey {
+    sensors: Vec<Sensor>,
+    /// The row to count impossible beacon positions on in part 1.
+    row: i64,
+    /// The largest coordinate the distress beacon can have in part 2.
+    max: i64,
+}
+
+impl Survey {
+    /// The row the real puzzle asks about in part 1.
+    pub const ROW: i64 = 2000000;
+    /// The largest coordinate the real puzzle allows in part 2.
+    pub const MAX: i64 = 4000000;
+
+    pub fn new(sensors: Vec<Sensor>, row: i64, max: i64) -> Self {
+        Survey { sensors, row, max }
+    }
+
+    /// The example in the puzzle text looks at a much smaller area than the real input, so it's
+    /// run with `row = 10` and `max = 20`.
+    pub fn parse(input: &str, params: &Params) -> Result<Self, ParseError> {
+        Ok(Survey::new(
+            parse_lines(input)?,
+            params.get("row", "a row")?.unwrap_or(Survey::ROW),
+            params.get("max", "a coordinate")?.unwrap_or(Survey::MAX),
+        ))
+    }
+}
+
+pub fn part1(sensors: &[Sensor], row: i64) -> usize {
+    let detection_range = sensors
+        .iter()
+        .map(|s| s.detection_range_x())
+        .reduce(|acc, item| {
+            let min = acc.start().min(item.start());
+            let max = acc.end().max(item.end());
+
+            *min..=*max
+        });
+
+    let Some(detection_range) = detection_range else {
+        return 0;
+    };
+
+    detection_range
+        .map(|x| Point::new(x, row))
+        .filter(|coords| sensors.iter().any(|s| s.is_impossible(*coords)))
+        .filter(|coords| sensors.iter().all(|s| s.closest_beacon != *coords))
+        .count()
+}
+
+pub fn part2(sensors: &[Sensor], max: i64) -> Option<i64> {
+    for y in 0..=max {
+        let ranges = sensors
+            .iter()
+            .filter_map(|s| s.detection_range_for_y(y))
+            .collect::<Vec<RangeInclusive<i64>>>();
+
+        let mut x = 0;
+
+        while x <= max {
+            if let Some(range) = ranges.iter().find(|r| r.contains(&x)) {
+                x = range.end() + 1;
+            } else {
+                return Some(x * 4000000 + y);
+            }
+        }
+    }
+
+    None
+}
+
+type Coords = Point<i64>;
+
+#[derive(Debug)]
+pub struct Sensor {
+    coords: Coords,
+    closest_beacon: Coords,
+}
+
+impl Sensor {
+    pub fn detection_range_x(&self) -> RangeInclusive<i64> {
+        let dist = self.coords.manhattan(self.closest_beacon);
+
+        self.coords.x - dist..=(self.coords.x + dist)
+    }
+
+    pub fn detection_range_for_y(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let dist = self.coords.manhattan(self.closest_beacon);
+
+        let y_diff = (self.coords.y - y).abs();
+        let x_diff = dist - y_diff;
+
+        if x_diff < 0 {
+            None
+        } else {
+            Some(self.coords.x - x_diff..=(self.coords.x + x_diff))
+        }
+    }
+
+    pub fn is_impossible(&self, coords: Coords) -> bool {
+        self.coords.manhattan(self.closest_beacon) >= self.coords.manhattan(coords)
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#"^Sensor at x=([-0-9]+), y=([-0-9]+): closest beacon is at x=([-0-9]+), y=([-0-9]+)$"#).unwrap()
+        });
+
+        let captures = RE.captures(s).ok_or_else(|| {
+            ParseError::new("\"Sensor at x=X, y=Y: closest beacon is at x=X, y=Y\"", s)
+        })?;
+        let coordinate = |i| {
+            let m = captures.get(i).unwrap();
+            parse_value::<i64>(m.as_str(), "a coordinate").map_err(|e| e.at_column(m.start() + 1))
+        };
+
+        let self_x = coordinate(1)?;
+        let self_y = coordinate(2)?;
+
+        let beacon_x = coordinate(3)?;
+        let beacon_y = coordinate(4)?;
+
+        Ok(Self {
+            coords: Point::new(self_x, self_y),
+            closest_beacon: Point::new(beacon_x, beacon_y),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use utils::{gen, geom::Point, Params, Solution};
+
+    use crate::{part1, part2, Day15, Sensor, Survey};
+
+    fn covers(sensor: &Sensor, coords: Point<i64>) -> bool {
+        sensor.coords.manhattan(coords) <= sensor.coords.manhattan(sensor.closest_beacon)
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_reference(input in gen::sensor_layout()) {
+            let params = Params::default().with("row", "10").with("max", "20");
+            let survey = Day15::parse_with(&input, &params).unwrap();
+            let sensors = &survey.sensors;
+
+            // Every sensor and beacon is within 0..=20, so nothing is covered further out than
+            // 40 either side of that.
+            let impossible = (-40..=60)
+                .map(|x| Point::new(x, survey.row))
+                .filter(|coords| sensors.iter().any(|s| covers(s, *coords)))
+                .filter(|coords| sensors.iter().all(|s| s.closest_beacon != *coords))
+                .count();
+            prop_assert_eq!(part1(sensors, survey.row), impossible);
+
+            let gap = (0..=survey.max)
+                .flat_map(|y| (0..=survey.max).map(move |x| Point::new(x, y)))
+                .find(|coords| sensors.iter().all(|s| !covers(s, *coords)))
+                .map(|coords| coords.x * 4000000 + coords.y);
+            prop_assert_eq!(part2(sensors, survey.max), gap);
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+
+        // Sensors close to the origin don't make it the example.
+        let survey = Day15::parse(input).unwrap();
+        assert_eq!((survey.row, survey.max), (Survey::ROW, Survey::MAX));
+
+        let survey = Day15::parse_with(input, &Params::default().with("row", "15")).unwrap();
+        assert_eq!((survey.row, survey.max), (15, Survey::MAX));
+
+        let err = Survey::parse(input, &Params::default().with("max", "big")).unwrap_err();
+        assert_eq!(err.expected, "a coordinate for max");
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{HashMap, HashSet};
+use utils::{parse_lines, search::bfs, trace, Answer, ParseError, Solution, SolveError};
+
+mod raw;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = FlattenedGraph;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let valves: Vec<raw::Valve> = parse_lines(input)?;
+        raw::check_connections(input, &valves)?;
+
+        Ok(FlattenedGraph::from_valves(valves))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part2(input)?.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Traversal<'a> {
+    visited: HashSet<&'a String>,
+    released_pressure: u32,
+    minute: u32,
+
+    limit: u32,
+}
+
+impl<'a> Traversal<'a> {
+    pub fn new(limit: u32) -> Traversal<'a> {
+        Self {
+            visited: HashSet::new(),
+            released_pressure: 0,
+            minute: 0,
+
+            limit,
+        }
+    }
+
+    pub fn step(&mut self, count: u32) {
+        self.minute += count;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.minute >= self.limit
+    }
+
+    pub fn visit_node(&mut self, node: &'a FlatNode) {
+        self.visited.insert(&node.ident);
+        self.released_pressure += node.flow_rate * (self.limit - self.minute);
+    }
+}
+
+pub fn part1(flattened: &FlattenedGraph) -> Result<u32, SolveError> {
+    let mut max: u32 = 0;
+    let mut traversals = vec![(&flattened.start, Traversal::new(30))];
+
+    while !traversals.is_empty() {
+        let (node, traversal) = traversals.remove(0);
+        trace::count("traversals explored");
+
+        for (ident, cost) in &node.connections {
+            if traversal.visited.contains(ident) {
+                continue;
+            }
+
+            let mut new = traversal.clone();
+            new.step(cost + 1);
+
+            if new.is_done() {
+                continue;
+            }
+
+            let next_node = flattened.node(ident)?;
+            new.visit_node(next_node);
+            traversals.push((next_node, new));
+        }
+
+        if traversal.released_pressure > max {
+            max = traversal.released_pressure;
+            trace::event("new best", || {
+                format!(
+                    "{max} released with {} valves open",
+                    traversal.visited.len()
+                )
+            });
+        }
+    }
+
+    Ok(max)
+}
+
+pub fn part2(flattened: &FlattenedGraph) -> Result<u32, SolveError> {
+    let mut finished = vec![];
+    let mut traversals = vec![(&flattened.start, Traversal::new(26))];
+
+    while !traversals.is_empty() {
+        let (node, traversal) = traversals.remove(0);
+        trace::count("traversals explored");
+
+        for (ident, cost) in &node.connections {
+            if traversal.visited.contains(ident) {
+                continue;
+            }
+
+            let mut new = traversal.clone();
+            new.step(cost + 1);
+
+            if new.is_done() {
+                continue;
+            }
+
+            let next_node = flattened.node(ident)?;
+            new.visit_node(next_node);
+            traversals.push((next_node, new));
+        }
+
+        finished.push(traversal);
+    }
+
+    // The max possible is the maximum of two paths, where the sets of visited valves are disjoint
+    let _span = trace::span("pairing paths");
+    trace::add(
+        "path pairs compared",
+        (finished.len() * (finished.len() + 1) / 2) as u64,
+    );
+
+    let mut max = 0;
+    for i in 0..finished.len() {
+        for j in i..finished.len() {
+            let first = &finished[i];
+            let second = &finished[j];
+
+            if !first.visited.is_disjoint(&second.visited) {
+                continue;
+            }
+
+            let sum = first.released_pressure + second.released_pressure;
+
+            if sum > max {
+                max = sum;
+            }
+        }
+    }
+
+    Ok(max)
+}
+
+#[derive(Debug)]
+pub struct FlattenedGraph {
+    start: FlatNode,
+    nodes: HashMap<String, FlatNode>,
+}
+
+#[derive(Debug)]
+struct FlatNode {
+    ident: String,
+    flow_rate: u32,
+    connections: HashMap<String, u32>,
+}
+
+impl FlattenedGraph {
+    /// The valve a connection leads to, which is always one worth opening.
+    fn node(&self, ident: &str) -> Result<&FlatNode, SolveError> {
+        self.nodes.get(ident).ok_or_else(|| {
+            SolveError::invariant(format!("valve {ident} is connected but can't be opened"))
+        })
+    }
+
+    pub fn from_valves(valves: Vec<raw::Valve>) -> Self {
+        let valves_with_flow_rate = valves
+            .iter()
+            .filter(|v| v.flow_rate != 0)
+            .collect::<HashSet<_>>();
+
+        let indexed = valves
+            .iter()
+            .map(|v| (v.ident.clone(), v))
+            .collect::<HashMap<_, _>>();
+
+        fn traverse_node(valve: &raw::Valve, all_nodes: &HashMap<String, &raw::Valve>) -> FlatNode {
+            // Find the distance to every other valve that's worth opening.
+            let search = bfs(valve.ident.as_str(), |ident| {
+                all_nodes[*ident].connections.iter().map(String::as_str)
+            });
+
+            FlatNode {
+                ident: valve.ident.clone(),
+                flow_rate: valve.flow_rate,
+                connections: search
+                    .costs
+                    .into_iter()
+                    .filter(|(ident, _)| *ident != valve.ident)
+                    .filter(|(ident, _)| all_nodes[*ident].flow_rate > 0)
+                    .map(|(ident, steps)| (ident.to_owned(), steps as u32))
+                    .collect(),
+            }
+        }
+
+        FlattenedGraph {
+            start: traverse_node(indexed["AA"], &indexed),
+            nodes: valves_with_flow_rate
+                .into_iter()
+                .map(|v| traverse_node(v, &indexed))
+                .map(|v| (v.ident.clone(), v))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+    use utils::{gen, parse_lines, ParseError, Solution};
+
+    use crate::{part1, part2, raw::Valve, Day16};
+
+    /// The most pressure released in `limit` minutes by someone who opens exactly the valves in
+    /// each set, found by trying every move or valve to open, minute by minute.
+    fn reference(input: &str, limit: u32) -> HashMap<u64, u32> {
+        let valves = parse_lines::<Valve>(input).unwrap();
+        let index = |ident: &str| valves.iter().position(|v| v.ident == ident).unwrap();
+
+        // The pressure released so far, by position and valves opened.
+        let mut states = HashMap::from([((index("AA"), 0u64), 0)]);
+        for minute in 1..=limit {
+            let mut next = HashMap::new();
+            let mut keep = |state, pressure: u32| {
+                let best = next.entry(state).or_insert(pressure);
+                *best = pressure.max(*best);
+            };
+
+            for (&(position, opened), &pressure) in &states {
+                keep((position, opened), pressure);
+
+                let valve = &valves[position];
+                if valve.flow_rate > 0 && opened & 1 << position == 0 {
+                    let released = valve.flow_rate * (limit - minute);
+                    keep((position, opened | 1 << position), pressure + released);
+                }
+
+                for tunnel in &valve.connections {
+                    keep((index(tunnel), opened), pressure);
+                }
+            }
+
+            states = next;
+        }
+
+        let mut best = HashMap::new();
+        for ((_, opened), pressure) in states {
+            let entry = best.entry(opened).or_insert(pressure);
+            *entry = pressure.max(*entry);
+        }
+
+        best
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_reference(input in gen::valve_graph()) {
+            let graph = Day16::parse(&input).unwrap();
+
+            let alone = reference(&input, 30);
+            prop_assert_eq!(part1(&graph), Ok(*alone.values().max().unwrap()));
+
+            // Working together, the elephant and you never open the same valve.
+            let together = reference(&input, 26);
+            let best_pair = together
+                .iter()
+                .flat_map(|(a, first)| {
+                    together
+                        .iter()
+                        .filter(move |(b, _)| *a & **b == 0)
+                        .map(move |(_, second)| first + second)
+                })
+                .max();
+            prop_assert_eq!(part2(&graph).ok(), best_pair);
+        }
+    }
+
+    #[test]
+    fn test_bad_connections() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB\n\
+                     \n\
+                     Valve BB has flow rate=3; tunnels lead to valves AA, ZZ\n";
+        assert_eq!(
+            Day16::parse(input).unwrap_err(),
+            ParseError {
+                line: Some(3),
+                column: Some(54),
+                text: "ZZ".into(),
+                expected: "a tunnel from BB to a known valve".into(),
+            }
+        );
+
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                     Valve BB has flow rate=3; tunnel leads to valve AA\n\
+                     Valve AA has flow rate=5; tunnel leads to valve BB\n";
+        assert_eq!(
+            Day16::parse(input).unwrap_err(),
+            ParseError {
+                line: Some(3),
+                column: Some(7),
+                text: "AA".into(),
+                expected: "a valve not already described on line 1".into(),
+            }
+        );
+    }
+}
@@ -1,7 +1,8 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+use utils::{lines, parse_value, ParseError};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Valve {
@@ -11,18 +12,25 @@ pub struct Valve {
 }
 
 static RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"Valve ([A-Z]{2}) has flow rate=([0-9]*); tunnels? leads? to valves? ([A-Z, ]*)"#)
+    Regex::new(r#"^Valve ([A-Z]{2}) has flow rate=([0-9]+); tunnels? leads? to valves? ([A-Z]{2}(, [A-Z]{2})*)$"#)
         .unwrap()
 });
 
 impl FromStr for Valve {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let captures = RE.captures(s).unwrap();
+        let captures = RE.captures(s).ok_or_else(|| {
+            ParseError::new(
+                "\"Valve XX has flow rate=N; tunnels lead to valves YY, ZZ\"",
+                s,
+            )
+        })?;
 
         let ident = captures.get(1).unwrap().as_str();
-        let flow_rate = captures.get(2).unwrap().as_str().parse().unwrap();
+        let flow_rate = captures.get(2).unwrap();
+        let flow_rate = parse_value(flow_rate.as_str(), "a flow rate")
+            .map_err(|e| e.at_column(flow_rate.start() + 1))?;
         let connections = captures.get(3).unwrap().as_str();
 
         Ok(Valve {
@@ -32,3 +40,43 @@ impl FromStr for Valve {
         })
     }
 }
+
+/// Makes sure the valves include the starting valve `AA`, are each described once and only lead
+/// to valves that exist. `valves` are the ones `parse_lines` found in `input`, one per non-blank
+/// line, which is how errors are traced back to their line.
+pub fn check_connections(input: &str, valves: &[Valve]) -> Result<(), ParseError> {
+    let exists = |ident: &str| valves.iter().any(|v| v.ident == ident);
+
+    if !exists("AA") {
+        return Err(ParseError::new("a valve named AA", ""));
+    }
+
+    let lines = lines(input).filter(|(_, line)| !line.is_empty());
+    let mut seen = HashMap::new();
+
+    for ((number, line), valve) in lines.zip(valves) {
+        if let Some(first) = seen.insert(valve.ident.as_str(), number) {
+            let expected = format!("a valve not already described on line {first}");
+            let ident = locate(line, &valve.ident);
+            return Err(ParseError::new(expected, ident)
+                .within(line, ident)
+                .at_line(number));
+        }
+
+        if let Some(missing) = valve.connections.iter().find(|conn| !exists(conn)) {
+            let expected = format!("a tunnel from {} to a known valve", valve.ident);
+            let missing = locate(line, missing);
+            return Err(ParseError::new(expected, missing)
+                .within(line, missing)
+                .at_line(number));
+        }
+    }
+
+    Ok(())
+}
+
+/// The slice of `line` a valve name was parsed from, so errors can point at it.
+fn locate<'a>(line: &'a str, ident: &'a str) -> &'a str {
+    line.find(ident)
+        .map_or(ident, |i| &line[i..i + ident.len()])
+}
@@ -0,0 +1,73 @@
+use std::fmt::{self, Display};
+
+use crate::grid::Grid;
+
+/// The result of solving a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A multi-line picture, such as the output of day 10's CRT, stored row by row.
+    Grid(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(val) => write!(f, "{val}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(val: $ty) -> Self {
+                    Answer::Integer(val as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl<T: Display> From<Grid<T>> for Answer {
+    fn from(grid: Grid<T>) -> Self {
+        Answer::Grid(
+            grid.rows()
+                .map(|row| row.iter().map(ToString::to_string).collect())
+                .collect(),
+        )
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Answer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42u32).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+
+        let grid = Answer::Grid(vec!["#..".into(), ".#.".into()]);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+    }
+}
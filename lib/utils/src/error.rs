@@ -0,0 +1,277 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// How much of the offending text is shown before it gets cut off.
+const MAX_TEXT_LEN: usize = 30;
+
+/// A puzzle input that doesn't have the expected shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the input the error occurred on, if known.
+    pub line: Option<usize>,
+    /// 1-based column within the line where the offending text starts, if known.
+    pub column: Option<usize>,
+    pub text: String,
+    /// A description of what should have been there instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(expected: impl Into<String>, text: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            column: None,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Sets the line the error occurred on, unless a more specific parser already did.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column the offending text starts at, unless a more specific parser already did.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Places an error from parsing `part`, which should be a slice of `line`, within `line`. A
+    /// column already set relative to `part` is shifted to be relative to `line` instead. If `part`
+    /// isn't part of `line`, there's no telling where it is, so the error is left without a column.
+    pub fn within(mut self, line: &str, part: &str) -> Self {
+        self.column = column_of(line, part).and_then(|start| {
+            self.column
+                .map_or(Some(start), |column| Some(start + column - 1))
+        });
+        self
+    }
+
+    /// Shifts the line number, for errors from a parser that was only given part of the input.
+    pub fn offset_lines(mut self, offset: usize) -> Self {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => {}
+        }
+
+        write!(f, "expected {}, got ", self.expected)?;
+
+        if self.text.chars().count() > MAX_TEXT_LEN {
+            let truncated = self.text.chars().take(MAX_TEXT_LEN).collect::<String>();
+            write!(f, "{truncated:?}...")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why a part couldn't produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input is well formed, but has no answer, such as a maze with no way out.
+    NoSolution(String),
+    /// Something the solution relies on about the input turned out not to hold.
+    Invariant(String),
+    /// The answer exists, but is too large for the type it's counted in.
+    Overflow(OverflowError),
+}
+
+impl SolveError {
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        SolveError::NoSolution(message.into())
+    }
+
+    pub fn invariant(message: impl Into<String>) -> Self {
+        SolveError::Invariant(message.into())
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "invalid input: {err}"),
+            SolveError::NoSolution(message) => write!(f, "no solution: {message}"),
+            SolveError::Invariant(message) => write!(f, "broken assumption: {message}"),
+            SolveError::Overflow(err) => write!(f, "overflow: {err}"),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(err) => Some(err),
+            SolveError::Overflow(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+/// A count that grew past the largest value of the type it was kept in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    /// What was being counted, such as "the calories of elf 3".
+    pub what: String,
+    /// 1-based line of the input where the count stopped fitting, if it was still being read.
+    pub line: Option<usize>,
+    /// The name of the type the count had to fit in.
+    pub width: &'static str,
+    /// How to count in a wider type instead, if there is one.
+    pub hint: Option<String>,
+}
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+
+        write!(f, "{} don't fit in {}", self.what, self.width)?;
+
+        match &self.hint {
+            Some(hint) => write!(f, " ({hint})"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for OverflowError {}
+
+impl From<OverflowError> for SolveError {
+    fn from(err: OverflowError) -> Self {
+        SolveError::Overflow(err)
+    }
+}
+
+/// The 1-based column at which `part` starts within `line`, or `None` if `part` isn't a slice of
+/// `line`.
+pub fn column_of(line: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(line.as_ptr() as usize)?;
+    if offset + part.len() > line.len() {
+        return None;
+    }
+
+    line.get(..offset).map(|before| before.chars().count() + 1)
+}
+
+/// Parses `s` with `FromStr`, describing the value as `expected` if it fails.
+pub fn parse_value<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(expected, s))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{column_of, parse_value, OverflowError, ParseError, SolveError};
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new("\"move N from A to B\"", "mvoe 3 from 1 to 2").at_line(37);
+        assert_eq!(
+            err.to_string(),
+            "line 37: expected \"move N from A to B\", got \"mvoe 3 from 1 to 2\""
+        );
+
+        let line = "addx five";
+        let err = parse_value::<i32>(&line[5..], "a number")
+            .unwrap_err()
+            .within(line, &line[5..])
+            .at_line(2);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a number, got \"five\""
+        );
+    }
+
+    #[test]
+    fn test_solve_error_display() {
+        let err: SolveError = ParseError::new("a number", "x").at_line(3).into();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: line 3: expected a number, got \"x\""
+        );
+
+        let err = SolveError::invariant("stack 2 is empty");
+        assert_eq!(err.to_string(), "broken assumption: stack 2 is empty");
+
+        let err: SolveError = OverflowError {
+            what: "the calories of elf 2".into(),
+            line: Some(4),
+            width: "u32",
+            hint: Some("count with --width u64".into()),
+        }
+        .into();
+        assert_eq!(
+            err.to_string(),
+            "overflow: line 4: the calories of elf 2 don't fit in u32 (count with --width u64)"
+        );
+    }
+
+    #[test]
+    fn test_nested_within() {
+        let line = "2-4,6-x";
+        let range = &line[4..];
+        let err = ParseError::new("a number", "x")
+            .within(range, &range[2..])
+            .within(line, range);
+
+        assert_eq!(err.column, Some(7));
+    }
+
+    #[test]
+    fn test_unrelated_part() {
+        let line = "héllo wörld";
+        assert_eq!(column_of(line, &line[7..]), Some(7));
+        assert_eq!(column_of(line, &String::from("wörld")), None);
+        assert_eq!(column_of(&line[7..], line), None);
+
+        let err = ParseError::new("a word", "x")
+            .at_column(2)
+            .within(line, "x");
+        assert_eq!(err.column, None);
+    }
+
+    #[test]
+    fn test_truncation() {
+        let err = ParseError::new("a digit", "x".repeat(40));
+        assert_eq!(
+            err.to_string(),
+            format!("expected a digit, got {:?}...", "x".repeat(30))
+        );
+    }
+
+    #[test]
+    fn test_innermost_position_wins() {
+        let err = ParseError::new("a number", "x")
+            .at_line(3)
+            .at_column(4)
+            .at_line(1)
+            .at_column(1);
+
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.column, Some(4));
+        assert_eq!(err.offset_lines(10).line, Some(13));
+    }
+}
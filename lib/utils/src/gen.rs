@@ -0,0 +1,132 @@
+use proptest::{collection::vec, prelude::*};
+
+/// Day 09: between 1 and 50 moves of the rope's head, like `R 4`.
+pub fn rope_moves() -> impl Strategy<Value = String> {
+    let movement = (prop_oneof!["L", "R", "U", "D"], 1..=20u32)
+        .prop_map(|(direction, steps)| format!("{direction} {steps}"));
+
+    vec(movement, 1..=50).prop_map(|moves| moves.join("\n"))
+}
+
+/// Day 10: a program of `noop` and `addx V` that runs for at least the 240 cycles the CRT draws.
+pub fn crt_program() -> impl Strategy<Value = String> {
+    let instruction = prop_oneof![
+        Just("noop".to_owned()),
+        (-20..=20i32).prop_map(|value| format!("addx {value}")),
+    ];
+
+    // Every instruction takes at least one cycle.
+    vec(instruction, 240..=300).prop_map(|program| program.join("\n"))
+}
+
+/// Day 13: a single packet, which is a list nested at most four deep.
+pub fn packet() -> impl Strategy<Value = String> {
+    let element = (0..=10u32)
+        .prop_map(|value| value.to_string())
+        .prop_recursive(4, 32, 5, |inner| {
+            vec(inner, 0..=5).prop_map(|elements| format!("[{}]", elements.join(",")))
+        });
+
+    vec(element, 0..=5).prop_map(|elements| format!("[{}]", elements.join(",")))
+}
+
+/// Day 13: between 1 and 20 pairs of packets, separated by blank lines.
+pub fn packet_pairs() -> impl Strategy<Value = String> {
+    vec((packet(), packet()), 1..=20).prop_map(|pairs| {
+        pairs
+            .iter()
+            .map(|(left, right)| format!("{left}\n{right}"))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    })
+}
+
+/// Day 14: between 1 and 10 rock paths near the sand's source at `500,0`, each made of straight
+/// horizontal or vertical lines. No rock is ever placed on the source itself.
+pub fn rock_paths() -> impl Strategy<Value = String> {
+    let path = (
+        490..=510usize,
+        1..=20usize,
+        vec((any::<bool>(), -6..=6isize), 1..=4),
+    )
+        .prop_map(|(x, y, lines)| {
+            let mut point = (x, y);
+            let mut points = vec![point];
+
+            for (horizontal, length) in lines {
+                match horizontal {
+                    true => point.0 = point.0.saturating_add_signed(length),
+                    false => point.1 = point.1.saturating_add_signed(length).max(1),
+                }
+                points.push(point);
+            }
+
+            points
+                .iter()
+                .map(|(x, y)| format!("{x},{y}"))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        });
+
+    vec(path, 1..=10).prop_map(|paths| paths.join("\n"))
+}
+
+/// Day 15: between 1 and 8 sensors, each with a beacon, all within `0..=20` on both axes like the
+/// puzzle's example.
+pub fn sensor_layout() -> impl Strategy<Value = String> {
+    let coords = || (0..=20i64, 0..=20i64);
+    let sensor = (coords(), coords()).prop_map(|((x, y), (beacon_x, beacon_y))| {
+        format!("Sensor at x={x}, y={y}: closest beacon is at x={beacon_x}, y={beacon_y}")
+    });
+
+    vec(sensor, 1..=8).prop_map(|sensors| sensors.join("\n"))
+}
+
+/// Day 16: between 2 and 6 valves named `AA`, `BB` and so on, joined by tunnels that lead both
+/// ways so that every valve can be reached from `AA`. Like the puzzle's, valve `AA` is stuck at a
+/// flow rate of 0.
+pub fn valve_graph() -> impl Strategy<Value = String> {
+    (2..=6usize)
+        .prop_flat_map(|count| {
+            // Each valve after the first is joined to an earlier one, and a few more tunnels are
+            // added on top.
+            let parents = (1..count).map(|valve| 0..valve).collect::<Vec<_>>();
+            let extra = vec((0..count, 0..count), 0..=count);
+
+            (vec(0..=25u32, count), parents, extra)
+        })
+        .prop_map(|(flow_rates, parents, extra)| {
+            let count = flow_rates.len();
+            let name = |valve: usize| char::from(b'A' + valve as u8).to_string().repeat(2);
+
+            let mut tunnels = vec![vec![]; count];
+            let pairs = parents
+                .into_iter()
+                .enumerate()
+                .map(|(i, parent)| (i + 1, parent));
+            for (a, b) in pairs.chain(extra) {
+                if a != b && !tunnels[a].contains(&b) {
+                    tunnels[a].push(b);
+                    tunnels[b].push(a);
+                }
+            }
+
+            (0..count)
+                .map(|valve| {
+                    let flow_rate = if valve == 0 { 0 } else { flow_rates[valve] };
+                    let leads_to = tunnels[valve].iter().map(|&v| name(v)).collect::<Vec<_>>();
+                    let tunnels = match leads_to.len() {
+                        1 => "tunnel leads to valve",
+                        _ => "tunnels lead to valves",
+                    };
+
+                    format!(
+                        "Valve {} has flow rate={flow_rate}; {tunnels} {}",
+                        name(valve),
+                        leads_to.join(", ")
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+}
@@ -0,0 +1,383 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    geom::{Direction, Point},
+    lines, ParseError,
+};
+
+/// A position in a grid, with `(0, 0)` in the top left corner.
+pub type Coords = Point<usize>;
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A rectangular window onto part of a grid.
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coords,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid by calling `f` with the position of every cell, row by row.
+    pub fn from_fn<F: FnMut(Coords) -> T>(width: usize, height: usize, mut f: F) -> Self {
+        Grid {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+                .map(&mut f)
+                .collect(),
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map(Vec::len).unwrap_or_default();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, one row per non-blank line, converting each character with `f`.
+    pub fn parse_with<F>(input: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (number, line) in lines(input).filter(|(_, line)| !line.is_empty()) {
+            let start = cells.len();
+
+            for (column, c) in line.chars().enumerate() {
+                let cell = f(c).map_err(|err| err.at_line(number).at_column(column + 1))?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                Some(width) if width != row_width => {
+                    let expected = format!("a row {width} characters wide");
+                    return Err(ParseError::new(expected, line).at_line(number));
+                }
+                _ => width = Some(row_width),
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(ParseError::new("a grid of characters", input)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coords: Coords) -> bool {
+        coords.x < self.width && coords.y < self.height
+    }
+
+    pub fn get(&self, coords: Coords) -> Option<&T> {
+        self.index_of(coords).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
+        self.index_of(coords).map(|idx| &mut self.cells[idx])
+    }
+
+    fn index_of(&self, coords: Coords) -> Option<usize> {
+        self.contains(coords)
+            .then_some(coords.y * self.width + coords.x)
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coords> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.coords().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, matching the predicate.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Coords> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coords, _)| coords)
+    }
+
+    /// The neighbouring position in the given direction, if that's still inside the grid.
+    pub fn step(&self, coords: Coords, direction: Direction) -> Option<Coords> {
+        let next = coords.checked_offset(direction.as_offset())?;
+        self.contains(next).then_some(next)
+    }
+
+    /// The orthogonal neighbours of a position that are inside the grid.
+    pub fn neighbours4(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(coords, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that are inside the grid.
+    pub fn neighbours8(&self, coords: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(coords, direction))
+    }
+
+    /// The positions reached by repeatedly stepping in `direction` from `start`, excluding
+    /// `start` itself, until the edge of the grid.
+    pub fn ray(&self, start: Coords, direction: Direction) -> impl Iterator<Item = Coords> + '_ {
+        std::iter::successors(self.step(start, direction), move |c| {
+            self.step(*c, direction)
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// A window of the given size with its top left corner at `origin`, or `None` if it would
+    /// extend past the edge of the grid.
+    pub fn view(&self, origin: Coords, width: usize, height: usize) -> Option<GridView<'_, T>> {
+        let fits = origin.x + width <= self.width && origin.y + height <= self.height;
+
+        fits.then_some(GridView {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at a position relative to the top left corner of the view.
+    pub fn get(&self, coords: Coords) -> Option<&'a T> {
+        if coords.x >= self.width || coords.y >= self.height {
+            return None;
+        }
+
+        self.grid.get(self.origin + coords)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|y| {
+            let row = self.grid.row(self.origin.y + y).unwrap();
+            &row[self.origin.x..self.origin.x + self.width]
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Coords> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coords: Coords) -> &T {
+        self.get(coords)
+            .unwrap_or_else(|| panic!("{coords} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Coords> for Grid<T> {
+    fn index_mut(&mut self, coords: Coords) -> &mut T {
+        self.get_mut(coords)
+            .unwrap_or_else(|| panic!("{coords} is outside the grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Ok)
+    }
+}
+
+fn write_rows<'a, T: Display + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> fmt::Result {
+    for (y, row) in rows.enumerate() {
+        if y > 0 {
+            writeln!(f)?;
+        }
+
+        for cell in row {
+            write!(f, "{cell}")?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Grid;
+    use crate::geom::{Direction, Point};
+
+    fn example() -> Grid<char> {
+        Grid::from_str("abc\ndef\n").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+
+        let err = Grid::from_str("abc\nde\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+
+        let err = Grid::parse_with("12\n3x", |c| {
+            c.to_digit(10).ok_or(crate::ParseError::new("a digit", c))
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = example();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.ray(Point::new(0, 0), Direction::Right)
+                .map(|c| grid[c])
+                .collect::<String>(),
+            "bc"
+        );
+    }
+
+    #[test]
+    fn test_transpose_and_view() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+
+        let view = grid.view(Point::new(1, 0), 2, 2).unwrap();
+        assert_eq!(view.to_string(), "bc\nef");
+        assert_eq!(view.get(Point::new(0, 1)), Some(&'e'));
+        assert!(grid.view(Point::new(2, 0), 2, 1).is_none());
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "fetch")]
+pub use http::{HttpFetcher, DEFAULT_BASE_URL, YEAR};
+
+/// Somewhere puzzle inputs can be downloaded from when they aren't cached yet.
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String, String>;
+}
+
+/// Why a day's input couldn't be provided.
+#[derive(Debug)]
+pub enum InputError {
+    /// The cache has no input for the day, or only an empty placeholder.
+    Missing {
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Fetch {
+        day: u8,
+        message: String,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { day, path } => {
+                write!(
+                    f,
+                    "No input for day {day:02}, expected it at {}",
+                    path.display()
+                )
+            }
+            InputError::Io { path, source } => {
+                write!(f, "Could not access {}: {source}", path.display())
+            }
+            InputError::Fetch { day, message } => {
+                write!(f, "Could not fetch the input for day {day:02}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A directory holding each day's input as `day_XX.txt`, kept apart from the solution crates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day_{day:02}.txt"))
+    }
+
+    /// The cached input for the day. Empty files are placeholders and count as missing.
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        let path = self.path(day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) if !input.trim().is_empty() => Ok(input),
+            Ok(_) => Err(InputError::Missing { day, path }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Err(InputError::Missing { day, path })
+            }
+            Err(source) => Err(InputError::Io { path, source }),
+        }
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.get(day).is_ok()
+    }
+
+    /// The cached input for the day, downloading and caching it first if it's missing. Inputs
+    /// that are already cached are never fetched again.
+    pub fn get_or_fetch(&self, day: u8, fetcher: &dyn Fetcher) -> Result<String, InputError> {
+        match self.get(day) {
+            Err(InputError::Missing { .. }) => {}
+            cached => return cached,
+        }
+
+        let input = fetcher
+            .fetch(day)
+            .map_err(|message| InputError::Fetch { day, message })?;
+
+        let path = self.path(day);
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, &input))
+            .map_err(|source| InputError::Io { path, source })?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(feature = "fetch")]
+mod http {
+    use std::{
+        sync::Mutex,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use super::Fetcher;
+
+    /// The site the puzzles are published on.
+    pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+    /// The event the solutions in this repository are for.
+    pub const YEAR: u16 = 2022;
+
+    /// Fetches inputs over HTTP using a logged in session, waiting between requests so the site
+    /// isn't hammered.
+    #[derive(Debug)]
+    pub struct HttpFetcher {
+        base_url: String,
+        session: String,
+        min_interval: Duration,
+        last_request: Mutex<Option<Instant>>,
+        agent: ureq::Agent,
+    }
+
+    impl HttpFetcher {
+        /// `session` is the value of the `session` cookie set when logging in to the site.
+        pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+            HttpFetcher {
+                base_url: base_url.into().trim_end_matches('/').to_owned(),
+                session: session.into(),
+                min_interval: Duration::from_secs(5),
+                last_request: Mutex::new(None),
+                agent: ureq::AgentBuilder::new()
+                    .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                    .timeout(Duration::from_secs(30))
+                    .build(),
+            }
+        }
+
+        /// Sets the shortest time allowed between two requests.
+        pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+            self.min_interval = min_interval;
+            self
+        }
+
+        pub fn url(&self, day: u8) -> String {
+            format!("{}/{YEAR}/day/{day}/input", self.base_url)
+        }
+
+        fn wait_for_turn(&self) {
+            let mut last_request = self.last_request.lock().unwrap();
+
+            if let Some(last) = *last_request {
+                let elapsed = last.elapsed();
+                if elapsed < self.min_interval {
+                    thread::sleep(self.min_interval - elapsed);
+                }
+            }
+
+            *last_request = Some(Instant::now());
+        }
+    }
+
+    impl Fetcher for HttpFetcher {
+        fn fetch(&self, day: u8) -> Result<String, String> {
+            self.wait_for_turn();
+
+            let response = self
+                .agent
+                .get(&self.url(day))
+                .set("Cookie", &format!("session={}", self.session))
+                .call()
+                .map_err(|err| match err {
+                    ureq::Error::Status(status, _) => format!("the server responded with {status}"),
+                    ureq::Error::Transport(err) => err.to_string(),
+                })?;
+
+            response
+                .into_string()
+                .map_err(|err| format!("could not read the response: {err}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, fs, path::PathBuf};
+
+    use super::{Fetcher, InputCache, InputError};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    struct FakeFetcher {
+        calls: Cell<usize>,
+    }
+
+    impl Fetcher for FakeFetcher {
+        fn fetch(&self, day: u8) -> Result<String, String> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("input for {day}\n"))
+        }
+    }
+
+    #[test]
+    fn test_missing() {
+        let dir = temp_dir("missing");
+        let cache = InputCache::new(&dir);
+
+        assert!(matches!(
+            cache.get(3),
+            Err(InputError::Missing { day: 3, .. })
+        ));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(cache.path(3), "\n").unwrap();
+        let err = cache.get(3).unwrap_err();
+        assert!(err.to_string().starts_with("No input for day 03"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let dir = temp_dir("fetch-once");
+        let cache = InputCache::new(&dir);
+        let fetcher = FakeFetcher {
+            calls: Cell::new(0),
+        };
+
+        assert_eq!(cache.get_or_fetch(7, &fetcher).unwrap(), "input for 7\n");
+        assert_eq!(cache.get_or_fetch(7, &fetcher).unwrap(), "input for 7\n");
+        assert_eq!(fetcher.calls.get(), 1);
+        assert_eq!(cache.get(7).unwrap(), "input for 7\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_http_fetcher() {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            thread,
+            time::{Duration, Instant},
+        };
+
+        use super::HttpFetcher;
+
+        // A stub server that answers two requests, echoing back what was asked for.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = vec![];
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    request.push(line);
+                }
+
+                let path = request[0].split(' ').nth(1).unwrap().to_owned();
+                let body = match request.iter().any(|h| h == "Cookie: session=secret") {
+                    true => format!("{path}\n"),
+                    false => String::new(),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        let fetcher =
+            HttpFetcher::new(base_url, "secret").with_min_interval(Duration::from_millis(100));
+        let start = Instant::now();
+
+        assert_eq!(fetcher.fetch(1).unwrap(), "/2022/day/1/input\n");
+        assert_eq!(fetcher.fetch(25).unwrap(), "/2022/day/25/input\n");
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        server.join().unwrap();
+    }
+}
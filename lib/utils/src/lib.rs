@@ -1,11 +1,20 @@
-use std::str::FromStr;
+mod answer;
+mod error;
+#[cfg(feature = "testing")]
+pub mod gen;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+mod parse;
+pub mod search;
+mod solution;
+pub mod trace;
 
-pub fn parse_lines<T, E>(data: &str) -> Result<Vec<T>, E>
-where
-    T: FromStr<Err = E>,
-{
-    data.split("\n")
-        .filter(|s| !s.is_empty())
-        .map(|s| T::from_str(s))
-        .collect()
-}
+pub use answer::Answer;
+pub use error::{column_of, parse_value, OverflowError, ParseError, SolveError};
+pub use parse::{
+    lines, normalize, parse_groups, parse_lines, parse_lines_with, parse_sections, sections,
+    Section,
+};
+pub use solution::{Params, Part, PartRun, Puzzle, Run, Solution};
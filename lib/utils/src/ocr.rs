@@ -0,0 +1,272 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use crate::grid::Grid;
+
+/// The 4x6 font most letter-drawing puzzles use. Some letters are narrower or wider than 4.
+const SMALL: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// How many columns each letter of the small font is given, including the gap after it.
+const SMALL_CELL: usize = 5;
+
+/// The larger 6x10 font used by a few puzzles.
+#[rustfmt::skip]
+const LARGE: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Why a picture couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// Neither font is this many rows high.
+    UnsupportedHeight(usize),
+    /// Nothing is drawn at all.
+    Blank,
+    /// The shape starting at this column isn't a known letter.
+    UnknownGlyph { column: usize },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => {
+                write!(f, "no font is {height} rows high, expected 6 or 10")
+            }
+            OcrError::Blank => write!(f, "the picture is blank"),
+            OcrError::UnknownGlyph { column } => {
+                write!(f, "unknown letter starting at column {column}")
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads the letters drawn by the lit cells of a grid, picking the font from its height.
+pub fn recognise(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let rows = grid.rows().map(<[bool]>::to_vec).collect::<Vec<_>>();
+    recognise_lit(&rows)
+}
+
+/// Reads the letters in rendered rows, where anything other than `.` or a space is lit.
+pub fn recognise_rows<S: AsRef<str>>(rows: &[S]) -> Result<String, OcrError> {
+    let rows = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(|c| c != '.' && c != ' ').collect())
+        .collect::<Vec<Vec<bool>>>();
+    recognise_lit(&rows)
+}
+
+fn recognise_lit(rows: &[Vec<bool>]) -> Result<String, OcrError> {
+    let font: Vec<_> = match rows.len() {
+        6 => SMALL.iter().map(|(c, glyph)| (*c, &glyph[..])).collect(),
+        10 => LARGE.iter().map(|(c, glyph)| (*c, &glyph[..])).collect(),
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut text = String::new();
+    for letter in letters(rows.len(), width, lit) {
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                letter
+                    .clone()
+                    .map(|x| match row.get(x) {
+                        Some(true) => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        text.push(lookup(&font, &glyph).ok_or(OcrError::UnknownGlyph {
+            column: letter.start,
+        })?);
+    }
+
+    match text.is_empty() {
+        true => Err(OcrError::Blank),
+        false => Ok(text),
+    }
+}
+
+/// The columns each letter is drawn in, without the blank columns around it.
+fn letters(height: usize, width: usize, lit: impl Fn(usize) -> bool) -> Vec<Range<usize>> {
+    match height {
+        // Small letters are drawn in cells 5 columns wide. Most leave the last column blank, but
+        // a wide letter like `Y` fills its cell and can touch the next one.
+        6 => (0..width)
+            .step_by(SMALL_CELL)
+            .filter_map(|cell| {
+                let columns = cell..(cell + SMALL_CELL).min(width);
+                let start = columns.clone().find(|x| lit(*x))?;
+                let end = columns.rev().find(|x| lit(*x))? + 1;
+
+                Some(start..end)
+            })
+            .collect(),
+        // Large letters are separated by blank columns and never contain one, so each run of
+        // columns with something drawn in them is a single letter.
+        _ => {
+            let mut letters = vec![];
+            let mut x = 0;
+            while x < width {
+                if !lit(x) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < width && lit(x) {
+                    x += 1;
+                }
+                letters.push(start..x);
+            }
+
+            letters
+        }
+    }
+}
+
+fn lookup(font: &[(char, &[&str])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, pattern)| trim(pattern) == glyph)
+        .map(|(c, _)| *c)
+}
+
+/// Removes the blank columns on either side of a letter, since some are drawn off-centre.
+fn trim(pattern: &[&str]) -> Vec<String> {
+    let width = pattern
+        .iter()
+        .map(|row| row.len())
+        .max()
+        .unwrap_or_default();
+    let lit = |x: usize| {
+        pattern
+            .iter()
+            .any(|row| row.as_bytes().get(x) == Some(&b'#'))
+    };
+
+    let start = (0..width).find(|x| lit(*x)).unwrap_or_default();
+    let end = (0..width).rfind(|x| lit(*x)).map_or(start, |x| x + 1);
+
+    pattern
+        .iter()
+        .map(|row| row[start..end].to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{recognise, recognise_rows, OcrError, LARGE, SMALL};
+    use crate::grid::Grid;
+
+    /// Draws every letter of a font in a row, each padded to `cell` columns.
+    fn alphabet(glyphs: &[(char, &[&str])], cell: usize) -> (String, Vec<String>) {
+        let height = glyphs[0].1.len();
+        let rows = (0..height)
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|(_, glyph)| format!("{:.<cell$}", glyph[y]))
+                    .collect::<String>()
+            })
+            .collect();
+
+        (glyphs.iter().map(|(c, _)| c).collect(), rows)
+    }
+
+    #[test]
+    fn test_fonts() {
+        let small = SMALL.iter().map(|(c, g)| (*c, &g[..])).collect::<Vec<_>>();
+        let (text, rows) = alphabet(&small, 5);
+        assert_eq!(recognise_rows(&rows), Ok(text));
+
+        let large = LARGE.iter().map(|(c, g)| (*c, &g[..])).collect::<Vec<_>>();
+        let (text, rows) = alphabet(&large, 8);
+        assert_eq!(recognise_rows(&rows), Ok(text));
+    }
+
+    #[test]
+    fn test_grid() {
+        let rows = [
+            "###  #  # #    #  #   ##  ##  ####  ##  ",
+            "#  # #  # #    #  #    # #  #    # #  # ",
+            "#  # #### #    ####    # #      #  #  # ",
+            "###  #  # #    #  #    # # ##  #   #### ",
+            "#    #  # #    #  # #  # #  # #    #  # ",
+            "#    #  # #### #  #  ##   ### #### #  # ",
+        ];
+        let grid = Grid::from_fn(40, 6, |p| rows[p.y].as_bytes()[p.x] == b'#');
+
+        assert_eq!(recognise(&grid), Ok("PHLHJGZA".into()));
+    }
+
+    #[test]
+    fn test_wide_letters() {
+        // `Y` fills its whole cell, so nothing separates it from a letter lit in its first column.
+        let rows = [
+            "#...##..#.###..",
+            "#...##..#.#..#.",
+            ".#.#.####.###..",
+            "..#..#..#.#..#.",
+            "..#..#..#.#..#.",
+            "..#..#..#.###..",
+        ];
+        assert_eq!(recognise_rows(&rows), Ok("YHB".into()));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            recognise_rows(&["#"; 7]),
+            Err(OcrError::UnsupportedHeight(7))
+        );
+        assert_eq!(recognise_rows(&["...."; 6]), Err(OcrError::Blank));
+        assert_eq!(
+            recognise_rows(&[
+                "#..#..##", "#..#..##", "####..##", "#..#..##", "#..#..##", "#..#..##"
+            ]),
+            Err(OcrError::UnknownGlyph { column: 6 })
+        );
+    }
+}
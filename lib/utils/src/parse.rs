@@ -0,0 +1,185 @@
+use std::{borrow::Cow, str::FromStr};
+
+use crate::ParseError;
+
+/// A run of consecutive non-blank lines, separated from the rest of the input by blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    /// 1-based line of the input the section starts on.
+    pub line: usize,
+}
+
+impl<'a> Section<'a> {
+    /// Parses the section with `f`, moving the line numbers of any error from being relative to
+    /// the section to being relative to the whole input.
+    pub fn parse_with<T, F>(&self, f: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&'a str) -> Result<T, ParseError>,
+    {
+        f(self.text).map_err(|err| match err.line {
+            Some(_) => err.offset_lines(self.line - 1),
+            None => err.at_line(self.line),
+        })
+    }
+}
+
+/// Converts CRLF line endings to LF and strips trailing whitespace from every line, only
+/// allocating if something needs to change.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    if input.split('\n').all(|line| line.trim_end() == line) {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(
+        input
+            .split('\n')
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Every line of the input with its 1-based line number, without line endings or trailing
+/// whitespace.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .split('\n')
+        .map(str::trim_end)
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+}
+
+/// Parses every non-blank line with `f`, tagging any error with the line it came from.
+pub fn parse_lines_with<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(number, line)| f(line).map_err(|err| err.at_line(number)))
+        .collect()
+}
+
+/// Parses every non-blank line with `FromStr`, tagging any error with the line it came from.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(input, T::from_str)
+}
+
+/// Splits the input into sections separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+
+    for (number, raw) in input.split('\n').enumerate() {
+        let line = raw.trim_end();
+
+        if line.is_empty() {
+            if let Some((line, start, end)) = current.take() {
+                sections.push(Section {
+                    text: &input[start..end],
+                    line,
+                });
+            }
+        } else {
+            let end = offset + line.len();
+            current = match current {
+                Some((line, start, _)) => Some((line, start, end)),
+                None => Some((number + 1, offset, end)),
+            };
+        }
+
+        offset += raw.len() + 1;
+    }
+
+    if let Some((line, start, end)) = current {
+        sections.push(Section {
+            text: &input[start..end],
+            line,
+        });
+    }
+
+    sections
+}
+
+/// Splits the input into exactly `N` sections, for inputs made of differently shaped parts.
+pub fn parse_sections<const N: usize>(input: &str) -> Result<[Section<'_>; N], ParseError> {
+    let sections = sections(input);
+    let count = sections.len();
+
+    sections.try_into().map_err(|_| {
+        let expected = format!("{N} sections separated by blank lines");
+        ParseError::new(expected, format!("{count} sections"))
+    })
+}
+
+/// Parses every blank-line-separated group of lines with `f`.
+pub fn parse_groups<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    sections(input)
+        .iter()
+        .map(|section| section.parse_with(&mut f))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_value, ParseError};
+
+    use super::{normalize, parse_groups, parse_lines_with, parse_sections, sections};
+
+    fn sum(group: &str) -> Result<u32, ParseError> {
+        parse_lines_with(group, |line| parse_value::<u32>(line, "a number"))
+            .map(|values| values.iter().sum())
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb  \n\n"), "a\nb\n\n");
+        assert!(matches!(normalize("a\nb\n"), std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "\n1\n2\n\n\n3\r\n  \r\n4\n";
+        let sections = sections(input);
+
+        assert_eq!(
+            sections
+                .iter()
+                .map(|s| (s.line, s.text))
+                .collect::<Vec<_>>(),
+            vec![(2, "1\n2"), (6, "3"), (8, "4")]
+        );
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        assert_eq!(parse_groups("1\n2\n\n3\r\n", sum), Ok(vec![3, 3]));
+
+        let err = parse_groups("1\n2\n\n3\nx\n", sum).unwrap_err();
+        assert_eq!(err.line, Some(5));
+        assert_eq!(err.text, "x");
+    }
+
+    #[test]
+    fn test_parse_sections() {
+        let [first, second] = parse_sections::<2>("a\nb\n\nc\n").unwrap();
+        assert_eq!(first.text, "a\nb");
+        assert_eq!(second.line, 4);
+
+        let err = parse_sections::<2>("a\n").unwrap_err();
+        assert_eq!(err.expected, "2 sections separated by blank lines");
+
+        let err = second
+            .parse_with(|s| Err::<(), _>(ParseError::new("d", s)))
+            .unwrap_err();
+        assert_eq!(err.line, Some(4));
+    }
+}
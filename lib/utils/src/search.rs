@@ -0,0 +1,248 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::{geom::Number, trace};
+
+/// Everything a search found: the cheapest known cost to each node it reached, and the node each
+/// one was reached from on that cheapest path.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub costs: HashMap<N, C>,
+    /// Maps every reached node other than the starting ones to the node before it.
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Search {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The path from one of the starting nodes to `target`, including both ends, or `None` if
+    /// `target` wasn't reached.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from a single node, where every step costs 1.
+pub fn bfs<N, F, I>(start: N, neighbours: F) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    bfs_from([start], neighbours)
+}
+
+/// Breadth-first search from several nodes at once, so each node's cost is its distance to the
+/// closest of them.
+pub fn bfs_from<N, S, F, I>(starts: S, mut neighbours: F) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.costs.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        trace::count("bfs nodes expanded");
+
+        for next in neighbours(&node) {
+            if search.costs.contains_key(&next) {
+                continue;
+            }
+
+            search.costs.insert(next.clone(), cost + 1);
+            search.predecessors.insert(next.clone(), node.clone());
+            queue.push_back((next, cost + 1));
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm from a single node, where `neighbours` gives each neighbour along with the
+/// non-negative cost of stepping to it.
+pub fn dijkstra<N, C, F, I>(start: N, neighbours: F) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Number,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (search, _) = best_first(start, neighbours, |_| C::ZERO, |_| false);
+    search
+}
+
+/// A* search for the cheapest path from `start` to any node satisfying `is_goal`, returning its
+/// cost and the path itself. `heuristic` must never overestimate the remaining cost.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    neighbours: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Number,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let (search, goal) = best_first(start, neighbours, heuristic, is_goal);
+    let goal = goal?;
+
+    Some((search.cost(&goal)?, search.path_to(&goal)?))
+}
+
+/// Expands nodes in order of their cost plus `heuristic`, until a node satisfying `is_goal` is
+/// expanded or everything reachable has been.
+fn best_first<N, C, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> (Search<N, C>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    C: Number,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    // Nodes don't have to be ordered, so the heap refers to them by their index in `nodes`.
+    let mut nodes = vec![];
+    let mut heap = BinaryHeap::new();
+
+    search.costs.insert(start.clone(), C::ZERO);
+    heap.push(Reverse((heuristic(&start), C::ZERO, 0)));
+    nodes.push(start);
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let node = nodes[idx].clone();
+
+        // Skip stale entries for nodes that were reached more cheaply after being queued.
+        if search.cost(&node).is_some_and(|best| best < cost) {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+
+        trace::count("best-first nodes expanded");
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+
+            if search.cost(&next).is_some_and(|best| best <= next_cost) {
+                continue;
+            }
+
+            search.costs.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+
+    (search, None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{astar, bfs, bfs_from, dijkstra};
+
+    /// A small weighted graph:
+    ///
+    /// ```text
+    /// a -1- b -1- c
+    ///  \          |
+    ///   ----5---- d -1- e
+    /// ```
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('d', 1)],
+            'd' => vec![('a', 5), ('c', 1), ('e', 1)],
+            'e' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        edges(node).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs('a', unweighted);
+
+        assert_eq!(search.cost(&'e'), Some(2));
+        assert_eq!(search.path_to(&'e'), Some(vec!['a', 'd', 'e']));
+        assert_eq!(search.path_to(&'z'), None);
+    }
+
+    #[test]
+    fn test_bfs_from() {
+        let search = bfs_from(['a', 'e'], unweighted);
+
+        assert_eq!(search.cost(&'c'), Some(2));
+        assert_eq!(search.cost(&'e'), Some(0));
+        assert_eq!(search.cost(&'d'), Some(1));
+        assert_eq!(search.path_to(&'b'), Some(vec!['a', 'b']));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra('a', edges);
+
+        assert_eq!(search.cost(&'d'), Some(3));
+        assert_eq!(search.path_to(&'e'), Some(vec!['a', 'b', 'c', 'd', 'e']));
+    }
+
+    #[test]
+    fn test_astar() {
+        let heuristic = |node: &char| ('e' as u32).abs_diff(*node as u32);
+        let found = astar('a', edges, heuristic, |node| *node == 'e');
+
+        assert_eq!(found, Some((4, vec!['a', 'b', 'c', 'd', 'e'])));
+        assert_eq!(astar('a', edges, |_| 0, |node| *node == 'z'), None);
+    }
+}
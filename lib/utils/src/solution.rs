@@ -0,0 +1,216 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{normalize, parse_value, trace, Answer, ParseError, SolveError};
+
+/// A single day's puzzle, split into a parsing step shared by both parts.
+pub trait Solution {
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    /// The parsed form of the input that both parts operate on.
+    type Input;
+
+    /// Turns the puzzle input into `Input`. When run through `Puzzle`, the input has already been
+    /// normalized to LF line endings without trailing whitespace.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// The names of the values a day reads from `Params`, for puzzles whose input doesn't say
+    /// everything, such as the size of the area to search.
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// Turns the puzzle input into `Input` with values for some of `PARAMS`, which is what the
+    /// runner calls. Days that take any fall back to the real puzzle's values for the rest, and
+    /// days whose input can be well formed and still not fit their types report that here.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, SolveError> {
+        Ok(Self::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// A description of the input beyond the answers, for days that have something to say.
+    fn report(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Values a day needs besides its input, by name, as the runner was given them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds every value in `other`, replacing any already given under the same name.
+    pub fn extend(&mut self, other: Params) {
+        self.0.extend(other.0);
+    }
+
+    /// The value of `name`, if it was given, read as `expected`.
+    pub fn get<T: FromStr>(&self, name: &str, expected: &str) -> Result<Option<T>, ParseError> {
+        self.0
+            .get(name)
+            .map(|value| parse_value(value, &format!("{expected} for {name}")))
+            .transpose()
+    }
+
+    /// Checks that every value is one of `known`, which a day can make sense of.
+    pub fn check(&self, known: &[&str]) -> Result<(), String> {
+        match self.0.keys().find(|name| !known.contains(&name.as_str())) {
+            Some(name) if known.is_empty() => Err(format!("takes no parameters, got {name}")),
+            Some(name) => Err(format!(
+                "has no parameter {name} (expected {})",
+                known.join(" or ")
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The outcome of running a puzzle, with the time spent in each step.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer, SolveError>,
+    pub duration: Duration,
+    /// Whatever the part recorded through `trace`, which is empty unless that is enabled.
+    pub stats: trace::Stats,
+}
+
+/// Object-safe view of a `Solution`, so the runner can hold every day in a single list.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    /// Parses the input and runs the given parts. Only a parse failure fails the whole run; a
+    /// part that fails doesn't stop the others.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, SolveError> {
+        self.run_with(input, parts, &Params::default())
+    }
+    /// Like `run`, parsing with `params`.
+    fn run_with(&self, input: &str, parts: &[Part], params: &Params) -> Result<Run, SolveError>;
+    /// The names of the parameters the day takes.
+    fn params(&self) -> &'static [&'static str];
+    /// Parses the input and describes it, or `None` if the day has no report.
+    fn report(&self, input: &str) -> Result<Option<String>, SolveError>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run_with(&self, input: &str, parts: &[Part], params: &Params) -> Result<Run, SolveError> {
+        let start = Instant::now();
+        let input = S::parse_with(&normalize(input), params)?;
+        let parse = start.elapsed();
+
+        // Only the parts are traced, so drop anything recorded while parsing, or left behind by a
+        // day that panicked on this thread.
+        trace::take();
+
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&input),
+                    Part::Two => S::part2(&input),
+                };
+
+                PartRun {
+                    part: *part,
+                    answer,
+                    duration: start.elapsed(),
+                    stats: trace::take(),
+                }
+            })
+            .collect();
+
+        Ok(Run { parse, parts })
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        S::PARAMS
+    }
+
+    fn report(&self, input: &str) -> Result<Option<String>, SolveError> {
+        Ok(S::report(&S::parse_with(
+            &normalize(input),
+            &Params::default(),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Params;
+
+    #[test]
+    fn test_params() {
+        let params = Params::default().with("row", "10").with("max", "x");
+
+        assert_eq!(params.get::<i64>("row", "a row"), Ok(Some(10)));
+        assert_eq!(params.get::<i64>("col", "a column"), Ok(None));
+        assert_eq!(
+            params
+                .get::<i64>("max", "a coordinate")
+                .unwrap_err()
+                .expected,
+            "a coordinate for max"
+        );
+
+        assert_eq!(params.check(&["row", "max", "col"]), Ok(()));
+        assert_eq!(
+            params.check(&["row"]),
+            Err("has no parameter max (expected row)".into())
+        );
+        assert_eq!(
+            params.check(&[]),
+            Err("takes no parameters, got max".into())
+        );
+    }
+}
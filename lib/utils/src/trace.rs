@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+#[cfg(not(feature = "trace"))]
+pub use disabled::{add, event, span, take, Span};
+#[cfg(feature = "trace")]
+pub use recording::{add, event, span, take, Span};
+
+/// Whether anything is recorded. Without the `trace` feature every function here does nothing
+/// and compiles away.
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+/// At most this many events are kept between calls to `take`, so a chatty loop can't eat all the
+/// memory. The rest are only counted.
+pub const EVENT_LIMIT: usize = 100;
+
+/// Everything recorded on a thread since the last call to `take`, in the order each name was
+/// first seen.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub counters: Vec<(&'static str, u64)>,
+    pub spans: Vec<(&'static str, SpanStats)>,
+    pub events: Vec<(&'static str, String)>,
+    /// Events that were left out for going over `EVENT_LIMIT`.
+    pub dropped_events: usize,
+}
+
+/// How often a span was entered, and the time spent in it altogether.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpanStats {
+    pub calls: u64,
+    pub total: Duration,
+}
+
+impl Stats {
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.spans.is_empty() && self.events.is_empty()
+    }
+
+    pub fn counter(&self, name: &str) -> Option<u64> {
+        self.counters
+            .iter()
+            .find(|(counter, _)| *counter == name)
+            .map(|(_, value)| *value)
+    }
+
+    #[cfg_attr(not(feature = "trace"), allow(dead_code))]
+    fn entry<'a, T: Default>(
+        entries: &'a mut Vec<(&'static str, T)>,
+        name: &'static str,
+    ) -> &'a mut T {
+        let idx = match entries.iter().position(|(entry, _)| *entry == name) {
+            Some(idx) => idx,
+            None => {
+                entries.push((name, T::default()));
+                entries.len() - 1
+            }
+        };
+
+        &mut entries[idx].1
+    }
+}
+
+/// Adds one to the counter `name`.
+#[inline(always)]
+pub fn count(name: &'static str) {
+    add(name, 1);
+}
+
+#[cfg(feature = "trace")]
+mod recording {
+    use std::{cell::RefCell, mem, time::Instant};
+
+    use super::{Stats, EVENT_LIMIT};
+
+    thread_local! {
+        static STATS: RefCell<Stats> = RefCell::default();
+    }
+
+    /// Adds `amount` to the counter `name`.
+    pub fn add(name: &'static str, amount: u64) {
+        STATS.with(|stats| *Stats::entry(&mut stats.borrow_mut().counters, name) += amount);
+    }
+
+    /// Logs an event. `message` is only called when events are being recorded.
+    pub fn event(name: &'static str, message: impl FnOnce() -> String) {
+        STATS.with(|stats| {
+            let mut stats = stats.borrow_mut();
+            match stats.events.len() < EVENT_LIMIT {
+                true => stats.events.push((name, message())),
+                false => stats.dropped_events += 1,
+            }
+        });
+    }
+
+    /// Times everything until the returned guard is dropped.
+    #[must_use = "the span ends as soon as the guard is dropped"]
+    pub fn span(name: &'static str) -> Span {
+        Span {
+            name,
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns everything recorded on this thread so far, and starts afresh.
+    pub fn take() -> Stats {
+        STATS.with(|stats| mem::take(&mut *stats.borrow_mut()))
+    }
+
+    pub struct Span {
+        name: &'static str,
+        start: Instant,
+    }
+
+    impl Drop for Span {
+        fn drop(&mut self) {
+            let elapsed = self.start.elapsed();
+            STATS.with(|stats| {
+                let mut stats = stats.borrow_mut();
+                let span = Stats::entry(&mut stats.spans, self.name);
+                span.calls += 1;
+                span.total += elapsed;
+            });
+        }
+    }
+}
+
+#[cfg(not(feature = "trace"))]
+mod disabled {
+    use super::Stats;
+
+    #[inline(always)]
+    pub fn add(_name: &'static str, _amount: u64) {}
+
+    #[inline(always)]
+    pub fn event(_name: &'static str, _message: impl FnOnce() -> String) {}
+
+    #[inline(always)]
+    #[must_use = "the span ends as soon as the guard is dropped"]
+    pub fn span(_name: &'static str) -> Span {
+        Span
+    }
+
+    #[inline(always)]
+    pub fn take() -> Stats {
+        Stats::default()
+    }
+
+    pub struct Span;
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod test {
+    use super::{count, event, span, take, EVENT_LIMIT};
+
+    #[test]
+    fn test_recording() {
+        take();
+
+        count("nodes");
+        super::add("nodes", 2);
+        count("edges");
+        for _ in 0..3 {
+            let _span = span("step");
+        }
+        for i in 0..EVENT_LIMIT + 5 {
+            event("visit", || i.to_string());
+        }
+
+        let stats = take();
+        assert_eq!(stats.counters, [("nodes", 3), ("edges", 1)]);
+        assert_eq!(stats.counter("nodes"), Some(3));
+        assert_eq!(stats.spans[0].0, "step");
+        assert_eq!(stats.spans[0].1.calls, 3);
+        assert_eq!(stats.events.len(), EVENT_LIMIT);
+        assert_eq!(stats.events[1], ("visit", "1".into()));
+        assert_eq!(stats.dropped_events, 5);
+
+        assert!(take().is_empty());
+    }
+}